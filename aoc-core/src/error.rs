@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Describes where and why a puzzle input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based number of the offending line.
+    pub line: usize,
+    /// 1-based column of the first character of the offending text.
+    pub column: usize,
+    /// The offending text, empty when something is missing.
+    pub text: String,
+    /// What the parser expected to find instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Reports that the input ended before `expected` could be found.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        let last_line = input.trim_end().lines().enumerate().last();
+        ParseError {
+            line: last_line.map_or(1, |(index, _)| index + 1),
+            column: last_line.map_or(1, |(_, text)| text.chars().count() + 1),
+            text: String::new(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Formats the error together with the offending line of `input`, marking
+    /// the offending text with carets.
+    pub fn render(&self, input: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            self.line,
+            source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
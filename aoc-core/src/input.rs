@@ -0,0 +1,101 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable overriding the directory the inputs are looked up in.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Reads a puzzle input from disk, normalizing Windows line endings so the
+/// parsers only ever have to deal with `\n`.
+pub fn read_input(path: impl AsRef<Path>) -> std::io::Result<String> {
+    Ok(std::fs::read_to_string(path)?.replace("\r\n", "\n"))
+}
+
+/// The directory holding the puzzle inputs, each named `day-<N>.txt`.
+///
+/// This is `$AOC_INPUT_DIR` when it is set. Otherwise it is the closest
+/// `inputs` directory in the working directory or one of its ancestors, so the
+/// runner works from anywhere inside the workspace.
+pub fn input_dir() -> PathBuf {
+    if let Some(input_dir) = std::env::var_os(INPUT_DIR_VARIABLE) {
+        return PathBuf::from(input_dir);
+    }
+    std::env::current_dir()
+        .ok()
+        .and_then(|working_dir| {
+            working_dir
+                .ancestors()
+                .map(|directory| directory.join(DEFAULT_INPUT_DIR))
+                .find(|input_dir| input_dir.is_dir())
+        })
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Name of the file holding the input of `day` in the [`input_dir`].
+pub fn input_file_name(day: u8) -> String {
+    format!("day-{}.txt", day)
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// The input of `day` in the [`input_dir`].
+    pub fn default_for(day: u8) -> Self {
+        InputSource::File(input_dir().join(input_file_name(day)))
+    }
+
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input.replace("\r\n", "\n"))
+            }
+            InputSource::File(path) => read_input(path),
+        }
+    }
+}
+
+/// `-` stands for the standard input, anything else is a path.
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(match source {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_str() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "inputs/day-1.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("inputs/day-1.txt")))
+        );
+    }
+}
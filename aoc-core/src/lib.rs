@@ -0,0 +1,154 @@
+mod error;
+mod input;
+mod solution;
+
+pub use error::ParseError;
+pub use input::{input_dir, input_file_name, read_input, InputSource, INPUT_DIR_VARIABLE};
+pub use solution::{Answer, Solution};
+
+use std::str::FromStr;
+
+/// A non-blank line of the puzzle input, remembering where it came from so that
+/// parse errors can point back at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number in the input.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Creates an error pointing at `token`, which should be a slice of the
+    /// line's text. Any other token is reported at the end of the line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            text: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for something missing from the end of the line.
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    /// Parses `token`, a slice of the line's text.
+    pub fn parse<T: FromStr>(&self, token: &'a str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parses every whitespace separated token of `text`, a slice of the
+    /// line's text, as a number.
+    pub fn parse_numbers<T: FromStr>(&self, text: &'a str) -> Result<Vec<T>, ParseError> {
+        text.split_whitespace()
+            .map(|token| self.parse(token, "a number"))
+            .collect()
+    }
+}
+
+/// Iterates over the lines of the input that contain anything besides whitespace.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(index, text)| Line {
+            number: index + 1,
+            text,
+        })
+}
+
+/// Splits the input into the blocks of lines separated by one or more blank
+/// lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections: Vec<Vec<Line>> = Vec::new();
+    let mut previous_line_number = 0;
+    for line in lines(input) {
+        match sections.last_mut() {
+            Some(section) if line.number == previous_line_number + 1 => section.push(line),
+            _ => sections.push(vec![line]),
+        }
+        previous_line_number = line.number;
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<'a>(lines: &[Line<'a>]) -> Vec<&'a str> {
+        lines.iter().map(|line| line.text).collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "\na\n   \nb\n\n";
+        let lines = lines(input).collect::<Vec<_>>();
+        assert_eq!(texts(&lines), ["a", "b"]);
+        assert_eq!(lines[1].number, 4);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+  \n\n
+ZZZ = (ZZZ, ZZZ)
+";
+        let sections = sections(input);
+        assert_eq!(
+            sections
+                .iter()
+                .map(|section| texts(section))
+                .collect::<Vec<_>>(),
+            [
+                vec!["RL"],
+                vec!["AAA = (BBB, CCC)", "BBB = (DDD, EEE)"],
+                vec!["ZZZ = (ZZZ, ZZZ)"]
+            ]
+        );
+        assert_eq!(sections[2][0].number, 9);
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let line = lines("Card 1: 41 48  6 | 7x").next().unwrap();
+        assert_eq!(
+            line.parse_numbers::<u32>(&line.text[7..16]),
+            Ok(vec![41, 48, 6])
+        );
+        assert_eq!(
+            line.parse_numbers::<u32>(&line.text[18..]),
+            Err(ParseError {
+                line: 1,
+                column: 20,
+                text: "7x".to_string(),
+                expected: "a number".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_error() {
+        let input = "32T3K 765\nT55X5 684\n";
+        let line = lines(input).nth(1).unwrap();
+        let error = line.error(&line.text[3..4], "a card label");
+        assert_eq!(
+            error.render(input),
+            "line 2, column 4: expected a card label, found `X`
+  |
+2 | T55X5 684
+  |    ^"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::InputArgs;
+use aoc_core::Solution;
+use clap::Subcommand;
+use day_5::Almanac;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A number, or a range of numbers written as `<start>..<end>` or
+/// `<start>..=<end>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numbers(Range<usize>);
+
+impl FromStr for Numbers {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let number = |text: &str| {
+            text.parse::<usize>()
+                .map_err(|_| format!("`{}` is not a number", text))
+        };
+        let range = if let Some((start, end)) = text.split_once("..=") {
+            number(start)?..number(end)?.saturating_add(1)
+        } else if let Some((start, end)) = text.split_once("..") {
+            number(start)?..number(end)?
+        } else {
+            let number = number(text)?;
+            number..number.saturating_add(1)
+        };
+        if range.is_empty() {
+            return Err(format!("`{}` is an empty range", text));
+        }
+        Ok(Numbers(range))
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Finds the seeds whose locations are in a range
+    Seeds {
+        /// Location, or range of locations as `<start>..<end>` or
+        /// `<start>..=<end>`
+        locations: Numbers,
+        /// Inverts the conversion between two other categories, named like
+        /// `seed-to-soil` or `soil-to-light`, instead of seed to location
+        #[arg(long)]
+        map: Option<String>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Shows the number a seed has in every category on its way to its
+    /// location, and the line of the range that converted it
+    Trace {
+        /// Seed, or range of seeds as `<start>..<end>` or `<start>..=<end>`
+        /// to show the pieces the range is split into
+        seeds: Numbers,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Lists overlapping, overflowing and uncovered ranges of the maps, and an
+    /// odd number of seeds
+    Check {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Seeds {
+            locations: Numbers(locations),
+            map,
+            input,
+        } => {
+            let almanac = crate::parse::<day_5::Puzzle>(5, &input)?;
+            let (map, description) = match map {
+                Some(name) => (
+                    almanac
+                        .map(&name)
+                        .ok_or(format!("the almanac has no maps for `{}`", name))?,
+                    format!("Numbers that {} converts", name),
+                ),
+                None => (
+                    almanac.seed_to_location_map(),
+                    "Seeds whose locations are".to_string(),
+                ),
+            };
+            let preimage = map.preimage(locations.clone());
+            println!("{} in {:?}:", description, locations);
+            if preimage.is_empty() {
+                println!("none");
+            }
+            for range in preimage {
+                println!("{:?}", range);
+            }
+            Ok(())
+        }
+        Command::Trace {
+            seeds: Numbers(seeds),
+            input,
+        } => {
+            let (source, text) = crate::read_input(5, &input)?;
+            let almanac = day_5::Puzzle::parse(&text)
+                .map_err(|error| format!("could not parse {}: {}", source, error.render(&text)))?;
+            let lines = text.lines().collect::<Vec<_>>();
+            let describe = |line: Option<usize>| match line {
+                Some(line) => format!("line {}: {}", line, lines[line - 1].trim()),
+                None => "identity".to_string(),
+            };
+            if seeds.len() == 1 {
+                println!("seed {}", seeds.start);
+                for step in almanac.trace(seeds.start) {
+                    println!(
+                        "{} {} ({})",
+                        step.category,
+                        step.number,
+                        describe(step.line)
+                    );
+                }
+                return Ok(());
+            }
+            println!("seed {:?}", seeds);
+            for (category, steps) in almanac.trace_ranges(seeds) {
+                println!("{}", category);
+                for step in steps {
+                    println!(
+                        "  {:?} -> {:?} ({})",
+                        step.source,
+                        step.destination,
+                        describe(step.line)
+                    );
+                }
+            }
+            Ok(())
+        }
+        Command::Check { input } => {
+            let (source, text) = crate::read_input(5, &input)?;
+            let almanac = Almanac::parse_unchecked(&text)
+                .map_err(|error| format!("could not parse {}: {}", source, error.render(&text)))?;
+            let findings = almanac.check();
+            for finding in &findings {
+                println!("{}", finding);
+            }
+            match findings
+                .iter()
+                .filter(|finding| finding.is_problem())
+                .count()
+            {
+                0 => {
+                    println!("{} has no problems", source);
+                    Ok(())
+                }
+                1 => Err(format!("{} has 1 problem", source)),
+                problems => Err(format!("{} has {} problems", source, problems)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!("46".parse(), Ok(Numbers(46..47)));
+        assert_eq!("46..60".parse(), Ok(Numbers(46..60)));
+        assert_eq!("46..=60".parse(), Ok(Numbers(46..61)));
+        assert!("60..46".parse::<Numbers>().is_err());
+        assert!("46..x".parse::<Numbers>().is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::toml_file;
+use aoc_core::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+const BASELINE_FILE: &str = "bench-baseline.toml";
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation and reallocation so that
+/// benchmarks can report them.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    #[serde(rename = "parse")]
+    Parse,
+    #[serde(rename = "part-1")]
+    Part1,
+    #[serde(rename = "part-2")]
+    Part2,
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub duration: Duration,
+    pub allocations: u64,
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Sample) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = black_box(f());
+    let duration = start.elapsed();
+    let sample = Sample {
+        duration,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (result, sample)
+}
+
+/// Measures parsing the input and solving each part `runs` times.
+pub fn bench<S: Solution>(
+    input: &str,
+    runs: usize,
+) -> Result<Vec<(Phase, Vec<Sample>)>, ParseError> {
+    let mut parse_samples = Vec::new();
+    let mut parsed = None;
+    for _ in 0..runs {
+        let (result, sample) = measure(|| S::parse(black_box(input)));
+        parse_samples.push(sample);
+        // Drop the previous input outside of the measurement.
+        parsed = Some(result?);
+    }
+    let Some(parsed) = parsed else {
+        return Ok(Vec::new());
+    };
+    let mut phases = vec![(Phase::Parse, parse_samples)];
+    for (phase, solve) in [
+        (Phase::Part1, S::part1 as fn(&_) -> _),
+        (Phase::Part2, S::part2),
+    ] {
+        let samples = (0..runs)
+            .map(|_| measure(|| solve(black_box(&parsed))).1)
+            .collect();
+        phases.push((phase, samples));
+    }
+    Ok(phases)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// Allocations of the median run.
+    pub allocations: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Sample]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_by_key(|sample| sample.duration);
+        let median = *samples.get(samples.len() / 2)?;
+        Some(Stats {
+            min: samples[0].duration,
+            median: median.duration,
+            max: samples[samples.len() - 1].duration,
+            allocations: median.allocations,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub median_ns: u64,
+    pub allocations: u64,
+}
+
+/// The medians of an earlier benchmark run, kept in `bench-baseline.toml` in
+/// the input directory.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "measurement")]
+    measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn path() -> PathBuf {
+        aoc_core::input_dir().join(BASELINE_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        toml_file::load_toml_or_default(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        toml_file::save_toml(path, self)
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.day == day && measurement.phase == phase)
+    }
+
+    /// Records a measurement, replacing the one of the same day and phase.
+    pub fn record(&mut self, measurement: Measurement) {
+        self.measurements
+            .retain(|other| (other.day, other.phase) != (measurement.day, measurement.phase));
+        self.measurements.push(measurement);
+    }
+}
+
+/// How a measurement compares with the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    /// Relative change of the median time, `0.1` being 10% slower.
+    pub time: f64,
+    pub allocations: i128,
+}
+
+impl Change {
+    pub fn between(baseline: &Measurement, measurement: &Measurement) -> Self {
+        Change {
+            time: (measurement.median_ns as f64 - baseline.median_ns as f64)
+                / baseline.median_ns.max(1) as f64,
+            allocations: measurement.allocations as i128 - baseline.allocations as i128,
+        }
+    }
+
+    /// Whether the measurement got slower by more than `threshold`, or
+    /// allocates more.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.time > threshold || self.allocations > 0
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{:+.1}% time, {:+} allocations",
+            self.time * 100.0,
+            self.allocations
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(micros: u64, allocations: u64) -> Sample {
+        Sample {
+            duration: Duration::from_micros(micros),
+            allocations,
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [sample(30, 3), sample(10, 1), sample(50, 5), sample(20, 2)];
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                min: Duration::from_micros(10),
+                median: Duration::from_micros(30),
+                max: Duration::from_micros(50),
+                allocations: 3,
+            })
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_regression() {
+        let measurement = |median_ns, allocations| Measurement {
+            day: 5,
+            phase: Phase::Part2,
+            median_ns,
+            allocations,
+        };
+        let mut baseline = Baseline::default();
+        baseline.record(measurement(1000, 10));
+        baseline.record(measurement(1000, 4));
+        let baseline = baseline.get(5, Phase::Part2).unwrap();
+        assert_eq!(baseline.allocations, 4);
+
+        let change = Change::between(baseline, &measurement(1200, 4));
+        assert_eq!(change.to_string(), "+20.0% time, +0 allocations");
+        assert!(change.is_regression(0.1));
+        assert!(!change.is_regression(0.25));
+        assert!(Change::between(baseline, &measurement(500, 5)).is_regression(0.1));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::config::Config;
+use std::fmt;
+
+const USER_AGENT: &str = "github.com/gergely-kovacs/advent-of-code-2023";
+
+#[derive(Debug)]
+pub enum Error {
+    /// No session token is configured.
+    MissingSession,
+    /// The server answered with an error status.
+    Status { code: u16, body: String },
+    /// The server could not be reached or its answer could not be read.
+    Transport(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session token, set `session` in aoc.toml or the AOC_SESSION environment variable"
+            ),
+            Error::Status { code, body } => {
+                write!(f, "the server answered with status {}: {}", code, body.trim())
+            }
+            Error::Transport(error) => write!(f, "could not reach the server: {}", error),
+        }
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(code, response) => Error::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => Error::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// Talks to an Advent of Code compatible server as a logged in user.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            year: config.year,
+            session: config.session.clone().ok_or(Error::MissingSession)?,
+        })
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the personal puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, Error> {
+        self.agent
+            .get(&format!("{}/input", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()
+            .map_err(|error| Error::Transport(error.to_string()))
+    }
+
+    /// Submits `answer` for one part of `day`, returning the server's response.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String, Error> {
+        self.agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(|error| Error::Transport(error.to_string()))
+    }
+}
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Environment variable pointing at the configuration file.
+const CONFIG_VARIABLE: &str = "AOC_CONFIG";
+/// Environment variable holding the session token, overriding the one in the
+/// configuration file.
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings for talking to the puzzle server, read from `aoc.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u16,
+    /// Address of the Advent of Code compatible server, without a trailing
+    /// slash.
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in user.
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: 2023,
+            base_url: "https://adventofcode.com".to_string(),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration from `$AOC_CONFIG`, or else from the closest
+    /// `aoc.toml` in the working directory or one of its ancestors. Without
+    /// either the defaults are used. `$AOC_SESSION` takes precedence over the
+    /// session in the file, and a blank session counts as none.
+    pub fn load() -> Result<Self, String> {
+        let path = std::env::var_os(CONFIG_VARIABLE)
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::current_dir().ok().and_then(|working_dir| {
+                    working_dir
+                        .ancestors()
+                        .map(|directory| directory.join(CONFIG_FILE))
+                        .find(|path| path.is_file())
+                })
+            });
+        let mut config = match path {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+                toml::from_str(&contents)
+                    .map_err(|error| format!("invalid {}: {}", path.display(), error))?
+            }
+            None => Config::default(),
+        };
+        if let Ok(session) = std::env::var(SESSION_VARIABLE) {
+            config.session = Some(session);
+        }
+        config.session = config.session.filter(|session| !session.trim().is_empty());
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str("base_url = \"http://localhost:8080\"").unwrap();
+        assert_eq!(
+            config,
+            Config {
+                base_url: "http://localhost:8080".to_string(),
+                ..Config::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::bench::{self, Phase, Sample};
+use aoc_core::{Answer, ParseError, Solution};
+
+/// Parses the input once and solves the requested parts of it.
+pub type Run = fn(&str, &[u8]) -> Result<Solved, ParseError>;
+
+/// Parses the input and solves both parts the given number of times,
+/// measuring each phase.
+pub type Bench = fn(&str, usize) -> Result<Vec<(Phase, Vec<Sample>)>, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub run: Run,
+    pub bench: Bench,
+}
+
+/// The answers to the requested parts, along with the warnings about the
+/// input.
+pub struct Solved {
+    pub answers: Vec<Answer>,
+    pub warnings: Vec<String>,
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(Solved {
+        answers: parts
+            .iter()
+            .map(|part| match part {
+                1 => S::part1(&parsed),
+                _ => S::part2(&parsed),
+            })
+            .collect(),
+        warnings: S::warnings(&parsed, parts),
+    })
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: run::<day_1::Puzzle>,
+        bench: bench::bench::<day_1::Puzzle>,
+    },
+    Day {
+        number: 2,
+        run: run::<day_2::Puzzle>,
+        bench: bench::bench::<day_2::Puzzle>,
+    },
+    Day {
+        number: 3,
+        run: run::<day_3::Puzzle>,
+        bench: bench::bench::<day_3::Puzzle>,
+    },
+    Day {
+        number: 4,
+        run: run::<day_4::Puzzle>,
+        bench: bench::bench::<day_4::Puzzle>,
+    },
+    Day {
+        number: 5,
+        run: run::<day_5::Puzzle>,
+        bench: bench::bench::<day_5::Puzzle>,
+    },
+    Day {
+        number: 6,
+        run: run::<day_6::Puzzle>,
+        bench: bench::bench::<day_6::Puzzle>,
+    },
+    Day {
+        number: 7,
+        run: run::<day_7::Puzzle>,
+        bench: bench::bench::<day_7::Puzzle>,
+    },
+    Day {
+        number: 8,
+        run: run::<day_8::Puzzle>,
+        bench: bench::bench::<day_8::Puzzle>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,99 @@
+use crate::client::{self, Client};
+use crate::config::Config;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already in the cache, the server was not contacted.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Client(client::Error),
+    Cache(PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Client(error) => write!(f, "{}", error),
+            Error::Cache(path, error) => write!(f, "could not write {}: {}", path.display(), error),
+        }
+    }
+}
+
+/// Makes sure the input of `day` is in `cache_dir`, downloading it when it is
+/// not there yet.
+pub fn fetch_input(config: &Config, day: u8, cache_dir: &Path) -> Result<Fetched, Error> {
+    let path = cache_dir.join(aoc_core::input_file_name(day));
+    if path.is_file() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = Client::new(config)
+        .and_then(|client| client.input(day))
+        .map_err(Error::Client)?;
+    std::fs::create_dir_all(cache_dir)
+        .and_then(|_| std::fs::write(&path, input))
+        .map_err(|error| Error::Cache(path.clone(), error))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{temp_dir, MockServer};
+
+    fn config(server: &MockServer) -> Config {
+        Config {
+            base_url: server.url.clone(),
+            session: Some("secret".to_string()),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_cache_miss_downloads_input() {
+        let server = MockServer::start(|_| (200, "Time: 7\nDistance: 9\n".to_string()));
+        let cache_dir = temp_dir("fetch-miss");
+        let fetched = fetch_input(&config(&server), 6, &cache_dir).unwrap();
+        let path = cache_dir.join("day-6.txt");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "Time: 7\nDistance: 9\n"
+        );
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/6/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_cache_hit_skips_request() {
+        let server = MockServer::start(|_| (200, "fresh".to_string()));
+        let cache_dir = temp_dir("fetch-hit");
+        std::fs::write(cache_dir.join("day-6.txt"), "cached").unwrap();
+        let fetched = fetch_input(&config(&server), 6, &cache_dir).unwrap();
+        assert_eq!(fetched, Fetched::Cached(cache_dir.join("day-6.txt")));
+        assert_eq!(
+            std::fs::read_to_string(cache_dir.join("day-6.txt")).unwrap(),
+            "cached"
+        );
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_http_error_is_not_cached() {
+        let server = MockServer::start(|_| (404, "Not Found".to_string()));
+        let cache_dir = temp_dir("fetch-error");
+        let error = fetch_input(&config(&server), 6, &cache_dir).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Client(client::Error::Status { code: 404, .. })
+        ));
+        assert!(!cache_dir.join("day-6.txt").exists());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::InputArgs;
+use aoc_core::Solution;
+use clap::{Subcommand, ValueEnum};
+use day_7::{CamelCardsRules, Distribution, Explanation, HandType};
+use serde::Serialize;
+
+/// Largest hand whose odds are worked out exactly, as that takes 13 times as
+/// long for every further card.
+const MAX_EXACT_HAND_SIZE: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Lists the hands of day 7 in rank order with why each got its rank and
+    /// winnings
+    Explain {
+        /// Part whose rules rank the hands, jokers being wild in part 2
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Tabulates the odds of each category for a uniformly random hand, with
+    /// and without jokers, both exactly and by simulation
+    Odds {
+        /// Cards in a hand. Hands of more than 6 cards are only simulated
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=8))]
+        hand_size: u8,
+        /// Random hands to draw for the simulation
+        #[arg(long, default_value_t = 100_000)]
+        samples: u64,
+        /// Seed of the simulation, the same seed drawing the same hands
+        #[arg(long, default_value_t = 2023)]
+        seed: u64,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct TieBreakRow {
+    position: usize,
+    label: char,
+    beaten_label: char,
+}
+
+#[derive(Debug, Serialize)]
+struct ExplanationRow {
+    rank: u64,
+    hand: String,
+    hand_type: String,
+    wild_cards_as: Option<char>,
+    tie_break: Option<TieBreakRow>,
+    bet: u32,
+    winnings: u64,
+}
+
+impl From<&Explanation> for ExplanationRow {
+    fn from(explanation: &Explanation) -> Self {
+        ExplanationRow {
+            rank: explanation.rank,
+            hand: explanation.labels.clone(),
+            hand_type: explanation.hand_type.to_string(),
+            wild_cards_as: explanation.wild_cards_as,
+            tie_break: explanation.tie_break.as_ref().map(|tie_break| TieBreakRow {
+                position: tie_break.position,
+                label: tie_break.label,
+                beaten_label: tie_break.beaten_label,
+            }),
+            bet: explanation.bet,
+            winnings: explanation.winnings,
+        }
+    }
+}
+
+fn to_table(explanations: &[Explanation]) -> String {
+    let mut table = vec![format!(
+        "{:>5}  {:<6} {:<16} {:<9} {:<18} {:>6} {:>10}",
+        "rank", "hand", "type", "wilds as", "tie-break", "bet", "winnings"
+    )];
+    for explanation in explanations {
+        table.push(format!(
+            "{:>5}  {:<6} {:<16} {:<9} {:<18} {:>6} {:>10}",
+            explanation.rank,
+            explanation.labels,
+            explanation.hand_type.to_string(),
+            explanation
+                .wild_cards_as
+                .map(String::from)
+                .unwrap_or_default(),
+            explanation
+                .tie_break
+                .as_ref()
+                .map(|tie_break| format!(
+                    "card {}: {} over {}",
+                    tie_break.position, tie_break.label, tie_break.beaten_label
+                ))
+                .unwrap_or_default(),
+            explanation.bet,
+            explanation.winnings
+        ));
+    }
+    table.push(format!(
+        "total winnings: {}",
+        explanations
+            .iter()
+            .map(|explanation| explanation.winnings)
+            .sum::<u64>()
+    ));
+    table
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The probability of each category under each set of rules, as percentages.
+fn odds_table(columns: &[(String, Distribution)]) -> String {
+    let mut table = vec![std::iter::once(format!("{:<16}", "type"))
+        .chain(columns.iter().map(|(name, _)| format!(" {:>16}", name)))
+        .collect::<String>()];
+    for hand_type in HandType::ALL {
+        table.push(
+            std::iter::once(format!("{:<16}", hand_type.to_string()))
+                .chain(columns.iter().map(|(_, distribution)| {
+                    format!(" {:>15.4}%", distribution.probability(hand_type) * 100.0)
+                }))
+                .collect(),
+        );
+    }
+    table.join("\n")
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Explain {
+            part,
+            format,
+            input,
+        } => {
+            let bids = crate::parse::<day_7::Puzzle>(7, &input)?;
+            for warning in day_7::Puzzle::warnings(&bids, &[part]) {
+                eprintln!("warning: {}", warning);
+            }
+            let rules = match part {
+                1 => CamelCardsRules::standard(),
+                _ => CamelCardsRules::jokers(),
+            };
+            let explanations = day_7::explain(&bids, &rules);
+            match format {
+                Format::Table => println!("{}", to_table(&explanations)),
+                Format::Json => {
+                    let rows = explanations
+                        .iter()
+                        .map(ExplanationRow::from)
+                        .collect::<Vec<_>>();
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&rows).map_err(|error| error.to_string())?
+                    )
+                }
+            }
+            Ok(())
+        }
+        Command::Odds {
+            hand_size,
+            samples,
+            seed,
+        } => {
+            let hand_size = hand_size as usize;
+            let mut columns = Vec::new();
+            for (name, rules) in [
+                ("", CamelCardsRules::standard()),
+                (" jokers", CamelCardsRules::jokers()),
+            ] {
+                if hand_size <= MAX_EXACT_HAND_SIZE {
+                    columns.push((
+                        format!("exact{}", name),
+                        day_7::exact_distribution(&rules, hand_size),
+                    ));
+                }
+                columns.push((
+                    format!("simulated{}", name),
+                    day_7::simulate_distribution(&rules, hand_size, samples, seed),
+                ));
+            }
+            println!("{}", odds_table(&columns));
+            if hand_size > MAX_EXACT_HAND_SIZE {
+                println!(
+                    "exact odds are only worked out for hands of up to {} cards",
+                    MAX_EXACT_HAND_SIZE
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_table() {
+        let bids = day_7::Puzzle::parse("32T3K 765\nKTJJT 220\nQQQJA 483").unwrap();
+        assert_eq!(
+            to_table(&day_7::explain(&bids, &CamelCardsRules::jokers())),
+            " rank  hand   type             wilds as  tie-break             bet   winnings
+    1  32T3K  one pair                                         765        765
+    2  QQQJA  four of a kind   Q                               483        966
+    3  KTJJT  four of a kind   T         card 1: K over Q      220        660
+total winnings: 2391"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::toml_file;
+use aoc_core::Answer;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const LEDGER_FILE: &str = "ledger.toml";
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after the previous one and was not
+    /// checked.
+    RateLimited,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the server checked the answer and rejected it.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let description = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+            Verdict::WrongLevel => "not the part the server expected",
+            Verdict::Unknown => "not understood",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer ever submitted, kept in `ledger.toml` in the input directory.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn path() -> PathBuf {
+        aoc_core::input_dir().join(LEDGER_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        toml_file::load_toml_or_default(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        toml_file::save_toml(path, self)
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    pub fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// The verdict the server already gave on `answer`, if it checked it.
+    pub fn known_verdict(&self, day: u8, part: u8, answer: &Answer) -> Option<Verdict> {
+        let answer = answer.to_string();
+        self.attempts(day, part)
+            .find(|attempt| {
+                attempt.answer == answer
+                    && (attempt.verdict == Verdict::Correct || attempt.verdict.is_wrong())
+            })
+            .map(|attempt| attempt.verdict)
+    }
+
+    /// Explains why a numeric `answer` cannot be right given the too high and
+    /// too low answers of earlier attempts.
+    pub fn bound_violation(&self, day: u8, part: u8, answer: &Answer) -> Option<String> {
+        let Answer::Number(answer) = answer else {
+            return None;
+        };
+        let numbers_with_verdict = |verdict| {
+            self.attempts(day, part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        if let Some(too_high) = numbers_with_verdict(Verdict::TooHigh).min() {
+            if *answer >= too_high {
+                return Some(format!("{} was already too high", too_high));
+            }
+        }
+        if let Some(too_low) = numbers_with_verdict(Verdict::TooLow).max() {
+            if *answer <= too_low {
+                return Some(format!("{} was already too low", too_low));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            day: 5,
+            part: 2,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_bound_violation() {
+        let mut ledger = Ledger::default();
+        ledger.record(attempt("100", Verdict::TooHigh));
+        ledger.record(attempt("80", Verdict::TooHigh));
+        ledger.record(attempt("20", Verdict::TooLow));
+        assert_eq!(
+            ledger.bound_violation(5, 2, &Answer::Number(90)),
+            Some("80 was already too high".to_string())
+        );
+        assert_eq!(
+            ledger.bound_violation(5, 2, &Answer::Number(20)),
+            Some("20 was already too low".to_string())
+        );
+        assert_eq!(ledger.bound_violation(5, 2, &Answer::Number(46)), None);
+        assert_eq!(ledger.bound_violation(5, 1, &Answer::Number(90)), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.record(attempt("46", Verdict::Correct));
+        ledger.record(attempt("47", Verdict::RateLimited));
+        let contents = toml::to_string(&ledger).unwrap();
+        assert_eq!(toml::from_str::<Ledger>(&contents).unwrap(), ledger);
+        assert_eq!(
+            ledger.known_verdict(5, 2, &Answer::Number(46)),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(ledger.known_verdict(5, 2, &Answer::Number(47)), None);
+    }
+}
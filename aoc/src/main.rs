@@ -0,0 +1,356 @@
+mod almanac;
+mod bench;
+mod client;
+mod config;
+mod days;
+mod fetch;
+mod hands;
+mod ledger;
+mod races;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod testing;
+mod toml_file;
+mod verify;
+
+use aoc_core::{Answer, InputSource, Solution};
+use bench::{Baseline, Change, Measurement, Stats};
+use clap::{Args, Parser, Subcommand};
+use config::Config;
+use fetch::Fetched;
+use ledger::Ledger;
+use scaffold::Examples;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use verify::{AcceptedAnswers, Outcome};
+
+#[global_allocator]
+static ALLOCATOR: bench::CountingAllocator = bench::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Puzzle input file, or `-` to read it from stdin. Defaults to
+    /// `day-<N>.txt` in `$AOC_INPUT_DIR`, or else in the closest `inputs`
+    /// directory above the working directory
+    #[arg(long)]
+    input: Option<InputSource>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solutions of a single day or of every day
+    Run {
+        /// Day to run, every day is run when omitted
+        #[arg(long)]
+        day: Option<u8>,
+        /// Part to run, both parts are run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Downloads the puzzle input of a day into the input directory, unless it
+    /// is already there
+    Fetch {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Submits the answer of one part and records the verdict in the ledger
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Runs the solutions on the real inputs and compares the answers with the
+    /// accepted ones in `answers.toml`
+    Verify {
+        /// Day to verify, every day is verified when omitted
+        #[arg(long)]
+        day: Option<u8>,
+        /// Part to verify, both parts are verified when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Times parsing and each part of a single day or of every day, and
+    /// compares the medians with the saved baseline
+    Bench {
+        /// Day to benchmark, every day is benchmarked when omitted
+        #[arg(long)]
+        day: Option<u8>,
+        /// How many times each phase is run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Slowdown of the median, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Saves the medians as the new baseline
+        #[arg(long)]
+        save_baseline: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Creates the crate of a new day, adds it to the workspace and registers
+    /// it with the runner
+    New {
+        #[arg(long)]
+        day: u8,
+        /// TOML file with an `[[example]]` table for each example, holding its
+        /// `input` and the expected `part-1` and `part-2` answers, used to
+        /// fill in the tests
+        #[arg(long)]
+        examples: Option<PathBuf>,
+    },
+    /// Looks into the almanac of day 5
+    Almanac {
+        #[command(subcommand)]
+        command: almanac::Command,
+    },
+    /// Looks into the boat races of day 6
+    Races {
+        #[command(subcommand)]
+        command: races::Command,
+    },
+    /// Looks into the Camel Cards hands of day 7
+    Hands {
+        #[command(subcommand)]
+        command: hands::Command,
+    },
+}
+
+/// Reads the input of a day, returning where it came from along with it.
+fn read_input(day: u8, input: &InputArgs) -> Result<(InputSource, String), String> {
+    let source = input
+        .input
+        .clone()
+        .unwrap_or_else(|| InputSource::default_for(day));
+    let text = source
+        .read()
+        .map_err(|error| format!("could not read {}: {}", source, error))?;
+    Ok((source, text))
+}
+
+/// Reads and parses the input of a day.
+fn parse<S: Solution>(day: u8, input: &InputArgs) -> Result<S::Input, String> {
+    let (source, input) = read_input(day, input)?;
+    S::parse(&input)
+        .map_err(|error| format!("could not parse {}: {}", source, error.render(&input)))
+}
+
+/// Solves the given parts of a day, printing the warnings about its input.
+fn solve(day: &days::Day, parts: &[u8], input: &InputArgs) -> Result<Vec<Answer>, String> {
+    let (source, input) = read_input(day.number, input)?;
+    let solved = (day.run)(&input, parts)
+        .map_err(|error| format!("could not parse {}: {}", source, error.render(&input)))?;
+    for warning in solved.warnings {
+        eprintln!("warning: {}: {}", source, warning);
+    }
+    Ok(solved.answers)
+}
+
+fn find_day(number: u8) -> Result<&'static days::Day, String> {
+    days::find(number).ok_or(format!("day {} is not solved yet", number))
+}
+
+fn run(day: Option<u8>, part: Option<u8>, input: InputArgs) -> Result<(), String> {
+    let selected_days = match day {
+        Some(number) => vec![find_day(number)?],
+        None if input.input.is_some() => return Err("--input needs a --day".to_string()),
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    for day in selected_days {
+        for (part, answer) in parts.iter().zip(solve(day, &parts, &input)?) {
+            println!("Day {} part {}: {}", day.number, part, answer);
+        }
+    }
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<(), String> {
+    let config = Config::load()?;
+    match fetch::fetch_input(&config, day, &aoc_core::input_dir())
+        .map_err(|error| error.to_string())?
+    {
+        Fetched::Cached(path) => {
+            println!("Day {} input is already cached at {}", day, path.display())
+        }
+        Fetched::Downloaded(path) => println!("Day {} input saved to {}", day, path.display()),
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, input: InputArgs) -> Result<(), String> {
+    let config = Config::load()?;
+    let answer = solve(find_day(day)?, &[part], &input)?.remove(0);
+    let ledger_path = Ledger::path();
+    let mut ledger = Ledger::load(&ledger_path)?;
+    if ledger.known_verdict(day, part, &answer).is_none() {
+        if let Some(violation) = ledger.bound_violation(day, part, &answer) {
+            eprintln!("warning: {} cannot be right, {}", answer, violation);
+        }
+    }
+    let verdict = submit::submit_answer(&config, &mut ledger, day, part, &answer)
+        .map_err(|error| error.to_string())?;
+    ledger.save(&ledger_path)?;
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+    Ok(())
+}
+
+fn verify(day: Option<u8>, part: Option<u8>) -> Result<(), String> {
+    let accepted_answers = AcceptedAnswers::load(&AcceptedAnswers::path())?;
+    let selected_days = match day {
+        Some(number) => vec![find_day(number)?],
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut mismatches = 0;
+    for day in selected_days {
+        let answers = solve(day, &parts, &InputArgs { input: None })?;
+        for (part, answer) in parts.iter().zip(answers) {
+            let accepted = accepted_answers.get(day.number, *part);
+            match verify::check(accepted, &answer) {
+                Outcome::Match => println!("Day {} part {}: ok", day.number, part),
+                Outcome::Mismatch => {
+                    mismatches += 1;
+                    println!("Day {} part {}: mismatch", day.number, part);
+                    println!("{}", verify::diff(accepted.unwrap_or_default(), &answer));
+                }
+                Outcome::Unrecorded => println!(
+                    "Day {} part {}: no accepted answer, got {}",
+                    day.number, part, answer
+                ),
+            }
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        1 => Err("1 answer does not match the accepted one".to_string()),
+        _ => Err(format!(
+            "{} answers do not match the accepted ones",
+            mismatches
+        )),
+    }
+}
+
+fn bench(
+    day: Option<u8>,
+    runs: u64,
+    threshold: f64,
+    save_baseline: bool,
+    input: InputArgs,
+) -> Result<(), String> {
+    let selected_days = match day {
+        Some(number) => vec![find_day(number)?],
+        None if input.input.is_some() => return Err("--input needs a --day".to_string()),
+        None => days::DAYS.iter().collect(),
+    };
+    let baseline_path = Baseline::path();
+    let mut baseline = Baseline::load(&baseline_path)?;
+    let mut regressions = 0;
+    for day in selected_days {
+        let (source, text) = read_input(day.number, &input)?;
+        let phases = (day.bench)(&text, runs as usize)
+            .map_err(|error| format!("could not parse {}: {}", source, error.render(&text)))?;
+        for (phase, samples) in phases {
+            let Some(stats) = Stats::from_samples(&samples) else {
+                continue;
+            };
+            let measurement = Measurement {
+                day: day.number,
+                phase,
+                median_ns: stats.median.as_nanos() as u64,
+                allocations: stats.allocations,
+            };
+            let mut report = format!(
+                "Day {} {:<6}  min {:>10}  median {:>10}  max {:>10}  allocations {:>8}",
+                day.number,
+                phase.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+                stats.allocations
+            );
+            if let Some(previous) = baseline.get(day.number, phase) {
+                let change = Change::between(previous, &measurement);
+                report.push_str(&format!("  ({})", change));
+                if change.is_regression(threshold / 100.0) {
+                    regressions += 1;
+                    report.push_str(" regression");
+                }
+            }
+            println!("{}", report);
+            baseline.record(measurement);
+        }
+    }
+    if save_baseline {
+        baseline.save(&baseline_path)?;
+        println!("Baseline saved to {}", baseline_path.display());
+    }
+    match regressions {
+        0 => Ok(()),
+        1 => Err("1 phase regressed from the baseline".to_string()),
+        _ => Err(format!(
+            "{} phases regressed from the baseline",
+            regressions
+        )),
+    }
+}
+
+fn new(day: u8, examples: Option<PathBuf>) -> Result<(), String> {
+    if days::find(day).is_some() {
+        return Err(format!("day {} is already registered", day));
+    }
+    let examples = match examples {
+        Some(path) => Examples::load(&path)?,
+        None => Examples::default(),
+    };
+    let crate_dir = scaffold::create_day(&scaffold::workspace_root()?, day, &examples)?;
+    println!(
+        "Day {} created in {} and registered with the runner",
+        day,
+        crate_dir.display()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part, input } => submit(day, part, input),
+        Command::Verify { day, part } => verify(day, part),
+        Command::Bench {
+            day,
+            runs,
+            threshold,
+            save_baseline,
+            input,
+        } => bench(day, runs, threshold, save_baseline, input),
+        Command::New { day, examples } => new(day, examples),
+        Command::Almanac { command } => almanac::run(command),
+        Command::Races { command } => races::run(command),
+        Command::Hands { command } => hands::run(command),
+    };
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
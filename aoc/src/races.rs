@@ -0,0 +1,252 @@
+use crate::InputArgs;
+use clap::{Subcommand, ValueEnum};
+use day_6::{BoatModel, Race};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Reports the best hold time, the winning hold times and the margin of
+    /// every race of day 6, and of the kerned race part 2 reads
+    Report {
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// Adds the distance of every hold time. In CSV this writes one row
+        /// per hold time instead of one per race
+        #[arg(long)]
+        curve: bool,
+        /// Most hold times to sample the curve of a race at, evenly spread
+        /// from holding for no time to holding for the whole race
+        #[arg(long, default_value_t = 101, value_parser = clap::value_parser!(u32).range(2..))]
+        samples: u32,
+        /// Speed gained per ms the button is held, in mm/ms
+        #[arg(long, default_value_t = 1)]
+        acceleration: u32,
+        /// Speed past which holding the button no longer charges the boat
+        #[arg(long)]
+        max_speed: Option<u32>,
+        /// Fraction of its speed the boat loses in every ms it moves, at least
+        /// 0 and below 1
+        #[arg(long, value_parser = parse_friction)]
+        friction: Option<f64>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+fn parse_friction(text: &str) -> Result<f64, String> {
+    let friction = text
+        .parse::<f64>()
+        .map_err(|_| format!("`{}` is not a number", text))?;
+    if !(0.0..1.0).contains(&friction) {
+        return Err(format!("{} is not at least 0 and below 1", friction));
+    }
+    Ok(friction)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct CurvePoint {
+    hold_time_ms: u32,
+    distance_mm: f64,
+    wins: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct RaceReport {
+    /// The race's position in the input, or `kerned` for the race of part 2.
+    race: String,
+    time_ms: u32,
+    record_mm: u64,
+    best_hold_time_ms: u32,
+    max_distance_mm: u128,
+    first_winning_hold_time_ms: Option<u32>,
+    last_winning_hold_time_ms: Option<u32>,
+    margin: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    curve: Option<Vec<CurvePoint>>,
+}
+
+/// The hold times to sample a race at: all of them, or `samples` of them
+/// evenly spread over the race.
+fn sample_hold_times(time_ms: u32, samples: u32) -> Vec<u32> {
+    if time_ms < samples {
+        return (0..=time_ms).collect();
+    }
+    (0..samples as u64)
+        .map(|sample| (sample * time_ms as u64 / (samples as u64 - 1)) as u32)
+        .collect()
+}
+
+fn report_race(
+    boat: &BoatModel,
+    name: String,
+    race: &Race,
+    curve_samples: Option<u32>,
+) -> RaceReport {
+    let analysis = boat.analyze(race);
+    RaceReport {
+        race: name,
+        time_ms: race.time_ms,
+        record_mm: race.distance_mm,
+        best_hold_time_ms: analysis.best_hold_time_ms,
+        max_distance_mm: analysis.max_distance_mm,
+        first_winning_hold_time_ms: analysis
+            .winning_hold_times
+            .first()
+            .map(|hold_times| hold_times.start),
+        last_winning_hold_time_ms: analysis
+            .winning_hold_times
+            .last()
+            .map(|hold_times| hold_times.end - 1),
+        margin: analysis.ways_to_win(),
+        curve: curve_samples.map(|samples| {
+            sample_hold_times(race.time_ms, samples)
+                .into_iter()
+                .map(|hold_time_ms| CurvePoint {
+                    hold_time_ms,
+                    distance_mm: boat.distance_mm(race.time_ms, hold_time_ms),
+                    wins: analysis
+                        .winning_hold_times
+                        .iter()
+                        .any(|hold_times| hold_times.contains(&hold_time_ms)),
+                })
+                .collect()
+        }),
+    }
+}
+
+fn optional(number: Option<u32>) -> String {
+    number.map(|number| number.to_string()).unwrap_or_default()
+}
+
+fn to_csv(reports: &[RaceReport]) -> String {
+    let mut csv = Vec::new();
+    if reports.iter().any(|report| report.curve.is_some()) {
+        csv.push("race,hold_time_ms,distance_mm,wins".to_string());
+        for report in reports {
+            for point in report.curve.iter().flatten() {
+                csv.push(format!(
+                    "{},{},{},{}",
+                    report.race, point.hold_time_ms, point.distance_mm, point.wins
+                ));
+            }
+        }
+    } else {
+        csv.push(
+            "race,time_ms,record_mm,best_hold_time_ms,max_distance_mm,\
+             first_winning_hold_time_ms,last_winning_hold_time_ms,margin"
+                .to_string(),
+        );
+        for report in reports {
+            csv.push(format!(
+                "{},{},{},{},{},{},{},{}",
+                report.race,
+                report.time_ms,
+                report.record_mm,
+                report.best_hold_time_ms,
+                report.max_distance_mm,
+                optional(report.first_winning_hold_time_ms),
+                optional(report.last_winning_hold_time_ms),
+                report.margin
+            ));
+        }
+    }
+    csv.join("\n")
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Report {
+            format,
+            curve,
+            samples,
+            acceleration,
+            max_speed,
+            friction,
+            input,
+        } => {
+            let races = crate::parse::<day_6::Puzzle>(6, &input)?;
+            let boat = BoatModel {
+                acceleration,
+                max_speed,
+                friction,
+            };
+            let curve_samples = curve.then_some(samples);
+            let mut reports = races
+                .races()
+                .iter()
+                .enumerate()
+                .map(|(index, race)| {
+                    report_race(&boat, (index + 1).to_string(), race, curve_samples)
+                })
+                .collect::<Vec<_>>();
+            match races.kerned_race() {
+                Ok(race) => reports.push(report_race(
+                    &boat,
+                    "kerned".to_string(),
+                    race,
+                    curve_samples,
+                )),
+                Err(error) => eprintln!("warning: no kerned race, {}", error),
+            }
+            match format {
+                Format::Csv => println!("{}", to_csv(&reports)),
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&reports).map_err(|error| error.to_string())?
+                ),
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_hold_times() {
+        assert_eq!(sample_hold_times(3, 5), [0, 1, 2, 3]);
+        assert_eq!(sample_hold_times(100, 5), [0, 25, 50, 75, 100]);
+    }
+
+    #[test]
+    fn test_parse_friction() {
+        assert_eq!(parse_friction("0.25"), Ok(0.25));
+        assert_eq!(parse_friction("0"), Ok(0.0));
+        for text in ["1", "1.5", "-0.1", "NaN", "x"] {
+            assert!(parse_friction(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let race = Race {
+            time_ms: 7,
+            distance_mm: 9,
+        };
+        let report = report_race(&BoatModel::default(), "1".to_string(), &race, None);
+        assert_eq!(
+            to_csv(std::slice::from_ref(&report)),
+            "race,time_ms,record_mm,best_hold_time_ms,max_distance_mm,\
+             first_winning_hold_time_ms,last_winning_hold_time_ms,margin
+1,7,9,3,12,2,5,4"
+        );
+        assert!(!serde_json::to_string(&report).unwrap().contains("curve"));
+
+        let report = report_race(&BoatModel::default(), "1".to_string(), &race, Some(3));
+        assert_eq!(
+            to_csv(&[report]),
+            "race,hold_time_ms,distance_mm,wins
+1,0,0,false
+1,3,12,true
+1,7,0,false"
+        );
+    }
+}
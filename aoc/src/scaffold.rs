@@ -0,0 +1,356 @@
+use crate::verify::AcceptedAnswer;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+/// An example from the puzzle description together with the answers it
+/// should give.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    input: String,
+    #[serde(rename = "part-1")]
+    part_1: Option<AcceptedAnswer>,
+    #[serde(rename = "part-2")]
+    part_2: Option<AcceptedAnswer>,
+}
+
+/// The examples of a new day, read from a TOML file with an `[[example]]`
+/// table for each of them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    examples: Vec<Example>,
+}
+
+impl Examples {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        toml::from_str(&contents).map_err(|error| format!("invalid {}: {}", path.display(), error))
+    }
+}
+
+/// Writes `text` as a string literal, keeping its line breaks the way the
+/// tests write example inputs.
+fn string_literal(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn render_answer(answer: &AcceptedAnswer) -> String {
+    match answer {
+        AcceptedAnswer::Number(number) => format!("Answer::Number({})", number),
+        AcceptedAnswer::Text(text) => format!("Answer::from({})", string_literal(text)),
+    }
+}
+
+fn render_test(name: &str, part: u8, input: &str, expected: &str, ignore: bool) -> String {
+    format!(
+        "
+    #[test]{}
+    fn {}() {{
+        let input = {};
+        assert_eq!(
+            Puzzle::part{}(&Puzzle::parse(input).unwrap()),
+            {}
+        );
+    }}
+",
+        if ignore {
+            "\n    #[ignore = \"needs an example from the puzzle description\"]"
+        } else {
+            ""
+        },
+        name,
+        string_literal(input.trim_end_matches('\n')),
+        part,
+        expected
+    )
+}
+
+/// Writes a test for every example answer of each part, or an ignored stub
+/// for a part without any.
+fn render_tests(examples: &Examples) -> String {
+    let mut tests = String::new();
+    for part in [1, 2] {
+        let part_examples = examples
+            .examples
+            .iter()
+            .filter_map(|example| {
+                let answer = match part {
+                    1 => example.part_1.as_ref(),
+                    _ => example.part_2.as_ref(),
+                }?;
+                Some((example.input.as_str(), render_answer(answer)))
+            })
+            .collect::<Vec<_>>();
+        if part_examples.is_empty() {
+            let name = format!("test_part_{}", part);
+            tests.push_str(&render_test(&name, part, "", "Answer::Unsolved", true));
+        }
+        for (index, (input, expected)) in part_examples.iter().enumerate() {
+            let name = match part_examples.len() {
+                1 => format!("test_part_{}", part),
+                _ => format!("test_part_{}_example_{}", part, index + 1),
+            };
+            tests.push_str(&render_test(&name, part, input, expected, false));
+        }
+    }
+    tests
+}
+
+fn render_lib(examples: &Examples) -> String {
+    LIB_TEMPLATE.replace("{{tests}}", &render_tests(examples))
+}
+
+fn render_manifest(day: u8) -> String {
+    MANIFEST_TEMPLATE.replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `new_line` after the last line matching `predicate`.
+fn insert_after_last_line(
+    text: &str,
+    predicate: impl Fn(&str) -> bool,
+    new_line: &str,
+) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let index = lines.iter().rposition(|line| predicate(line))?;
+    lines.insert(index + 1, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn is_day_line(line: &str, separator: &str) -> bool {
+    line.strip_prefix("day-")
+        .and_then(|rest| rest.split_once(separator))
+        .is_some_and(|(day, _)| !day.is_empty() && day.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Adds the crate to the members and the dependencies of the workspace
+/// manifest.
+fn add_to_workspace(manifest: &str, day: u8) -> Result<String, String> {
+    let name = format!("day-{}", day);
+    if manifest.contains(&format!("\"{}\"", name)) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+    let manifest = insert_after_last_line(
+        manifest,
+        |line| is_day_line(line.trim().trim_start_matches('"'), "\","),
+        &format!("    \"{}\",", name),
+    )
+    .ok_or("no day crates among the workspace members")?;
+    insert_after_last_line(
+        &manifest,
+        |line| is_day_line(line, " = { path = "),
+        &format!("{} = {{ path = \"{}\" }}", name, name),
+    )
+    .ok_or("no day crates among the workspace dependencies".to_string())
+}
+
+/// Adds the crate to the dependencies of the runner.
+fn add_to_runner(manifest: &str, day: u8) -> Result<String, String> {
+    insert_after_last_line(
+        manifest,
+        |line| is_day_line(line, ".workspace = true"),
+        &format!("day-{}.workspace = true", day),
+    )
+    .ok_or("no day crates among the runner dependencies".to_string())
+}
+
+/// Adds the day to the end of the runner's registry.
+fn register_day(registry: &str, day: u8) -> Result<String, String> {
+    let start = registry
+        .find("pub const DAYS: &[Day] = &[")
+        .ok_or("the registry has no `DAYS`")?;
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .ok_or("the registry `DAYS` is not closed")?;
+    Ok(format!(
+        "{}
+    Day {{
+        number: {day},
+        run: run::<day_{day}::Puzzle>,
+        bench: bench::bench::<day_{day}::Puzzle>,
+    }},{}",
+        &registry[..end],
+        &registry[end..],
+        day = day
+    ))
+}
+
+/// Finds the workspace manifest in the working directory or one of its
+/// ancestors.
+pub fn workspace_root() -> Result<PathBuf, String> {
+    let working_dir = std::env::current_dir().map_err(|error| error.to_string())?;
+    working_dir
+        .ancestors()
+        .find(|directory| {
+            std::fs::read_to_string(directory.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or("not inside the workspace".to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|error| format!("could not read {}: {}", path.display(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents)
+        .map_err(|error| format!("could not write {}: {}", path.display(), error))
+}
+
+/// Creates the crate of a new day in the workspace at `root`, adds it to the
+/// workspace and registers it with the runner. Returns the crate's directory.
+pub fn create_day(root: &Path, day: u8, examples: &Examples) -> Result<PathBuf, String> {
+    let crate_dir = root.join(format!("day-{}", day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let registry_path = root.join("aoc").join("src").join("days.rs");
+    // Prepare every change before writing anything, so that a failure leaves
+    // the workspace untouched.
+    let workspace = add_to_workspace(&read(&workspace_path)?, day)?;
+    let runner = add_to_runner(&read(&runner_path)?, day)?;
+    let registry = register_day(&read(&registry_path)?, day)?;
+
+    let source_dir = crate_dir.join("src");
+    std::fs::create_dir_all(&source_dir)
+        .map_err(|error| format!("could not create {}: {}", source_dir.display(), error))?;
+    write(&crate_dir.join("Cargo.toml"), &render_manifest(day))?;
+    write(&source_dir.join("lib.rs"), &render_lib(examples))?;
+    write(&workspace_path, &workspace)?;
+    write(&runner_path, &runner)?;
+    write(&registry_path, &registry)?;
+    Ok(crate_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::temp_dir;
+
+    #[test]
+    fn test_render_tests() {
+        let examples: Examples = toml::from_str(
+            r#"
+[[example]]
+input = """
+LLR
+
+AAA = (BBB, BBB)
+"""
+part-1 = 6
+
+[[example]]
+input = "RL"
+part-1 = 2
+"#,
+        )
+        .unwrap();
+        let tests = render_tests(&examples);
+        assert!(tests.contains(
+            "
+    #[test]
+    fn test_part_1_example_1() {
+        let input = \"LLR
+
+AAA = (BBB, BBB)\";
+        assert_eq!(
+            Puzzle::part1(&Puzzle::parse(input).unwrap()),
+            Answer::Number(6)
+        );
+    }
+"
+        ));
+        assert!(tests.contains("fn test_part_1_example_2()"));
+        assert!(tests.contains(
+            "
+    #[test]
+    #[ignore = \"needs an example from the puzzle description\"]
+    fn test_part_2() {"
+        ));
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temp_dir("scaffold");
+        std::fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]
+members = [
+    \"aoc\",
+    \"day-1\",
+]
+
+[workspace.dependencies]
+day-1 = { path = \"day-1\" }
+",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday-1.workspace = true\nserde.workspace = true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: run::<day_1::Puzzle>,
+        bench: bench::bench::<day_1::Puzzle>,
+    },
+];
+",
+        )
+        .unwrap();
+
+        let crate_dir = create_day(&root, 2, &Examples::default()).unwrap();
+        assert!(std::fs::read_to_string(crate_dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day-2\""));
+        assert!(crate_dir.join("src").join("lib.rs").is_file());
+        assert_eq!(
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]
+members = [
+    \"aoc\",
+    \"day-1\",
+    \"day-2\",
+]
+
+[workspace.dependencies]
+day-1 = { path = \"day-1\" }
+day-2 = { path = \"day-2\" }
+"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap(),
+            "[dependencies]\nday-1.workspace = true\nday-2.workspace = true\nserde.workspace = true\n"
+        );
+        assert!(
+            std::fs::read_to_string(root.join("aoc").join("src").join("days.rs"))
+                .unwrap()
+                .ends_with(
+                    "
+    Day {
+        number: 2,
+        run: run::<day_2::Puzzle>,
+        bench: bench::bench::<day_2::Puzzle>,
+    },
+];
+"
+                )
+        );
+        assert!(create_day(&root, 2, &Examples::default()).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::client::{self, Client};
+use crate::config::Config;
+use crate::ledger::{Attempt, Ledger, Verdict};
+use aoc_core::Answer;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug)]
+pub enum Error {
+    Unsolved,
+    /// The answer was accepted before, there is nothing left to do.
+    AlreadyCorrect,
+    /// The answer was rejected before, submitting it again would only trigger
+    /// the rate limit.
+    KnownWrong(Verdict),
+    Client(client::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Unsolved => write!(f, "the part is not solved yet, there is nothing to submit"),
+            Error::AlreadyCorrect => write!(f, "the answer was already accepted"),
+            Error::KnownWrong(verdict) => write!(
+                f,
+                "the answer was already rejected as {}, not submitting it again",
+                verdict
+            ),
+            Error::Client(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the server answers a submission with.
+pub fn parse_verdict(response: &str) -> Verdict {
+    if response.contains("That's the right answer") {
+        Verdict::Correct
+    } else if response.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if response.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else if response.contains("That's not the right answer") {
+        if response.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Submits `answer` unless the ledger already knows its verdict, and records
+/// the attempt in the ledger.
+pub fn submit_answer(
+    config: &Config,
+    ledger: &mut Ledger,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<Verdict, Error> {
+    if *answer == Answer::Unsolved {
+        return Err(Error::Unsolved);
+    }
+    match ledger.known_verdict(day, part, answer) {
+        Some(Verdict::Correct) => return Err(Error::AlreadyCorrect),
+        Some(verdict) => return Err(Error::KnownWrong(verdict)),
+        None => {}
+    }
+    let answer = answer.to_string();
+    let response = Client::new(config)
+        .and_then(|client| client.submit(day, part, &answer))
+        .map_err(Error::Client)?;
+    let verdict = parse_verdict(&response);
+    ledger.record(Attempt {
+        day,
+        part,
+        answer,
+        verdict,
+        submitted_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs()),
+    });
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer \
+        to restoring snow operations.</p></article>";
+    const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again. You have 42s left to wait.</p></article>";
+
+    fn config(server: &MockServer) -> Config {
+        Config {
+            base_url: server.url.clone(),
+            session: Some("secret".to_string()),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_submission_is_recorded() {
+        let server = MockServer::start(|request| match request.body.as_str() {
+            "level=2&answer=47" => (200, TOO_HIGH.to_string()),
+            _ => (200, RIGHT.to_string()),
+        });
+        let mut ledger = Ledger::default();
+        assert_eq!(
+            submit_answer(&config(&server), &mut ledger, 5, 2, &Answer::Number(47)).unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            submit_answer(&config(&server), &mut ledger, 5, 2, &Answer::Number(46)).unwrap(),
+            Verdict::Correct
+        );
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/5/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        let verdicts = ledger
+            .attempts(5, 2)
+            .map(|attempt| (attempt.answer.as_str(), attempt.verdict))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            verdicts,
+            [("47", Verdict::TooHigh), ("46", Verdict::Correct)]
+        );
+    }
+
+    #[test]
+    fn test_known_answers_are_not_resubmitted() {
+        let server = MockServer::start(|_| (200, TOO_HIGH.to_string()));
+        let mut ledger = Ledger::default();
+        submit_answer(&config(&server), &mut ledger, 5, 2, &Answer::Number(47)).unwrap();
+        assert!(matches!(
+            submit_answer(&config(&server), &mut ledger, 5, 2, &Answer::Number(47)),
+            Err(Error::KnownWrong(Verdict::TooHigh))
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limited_answer_can_be_retried() {
+        let server = MockServer::start(|_| (200, TOO_RECENTLY.to_string()));
+        let mut ledger = Ledger::default();
+        for _ in 0..2 {
+            assert_eq!(
+                submit_answer(&config(&server), &mut ledger, 5, 2, &Answer::Number(46)).unwrap(),
+                Verdict::RateLimited
+            );
+        }
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            parse_verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict("That's not the right answer. If you're stuck"),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_verdict(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(parse_verdict("<html>Maintenance</html>"), Verdict::Unknown);
+    }
+}
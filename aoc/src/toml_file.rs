@@ -0,0 +1,25 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// Reads a TOML file, starting from the default value when the file does not
+/// exist.
+pub fn load_toml_or_default<T: DeserializeOwned + Default>(path: &Path) -> Result<T, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents)
+            .map_err(|error| format!("invalid {}: {}", path.display(), error)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(error) => Err(format!("could not read {}: {}", path.display(), error)),
+    }
+}
+
+/// Writes `value` as TOML, creating the directories leading to the file.
+pub fn save_toml<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let contents = toml::to_string(value).map_err(|error| error.to_string())?;
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)
+            .map_err(|error| format!("could not create {}: {}", directory.display(), error))?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|error| format!("could not write {}: {}", path.display(), error))
+}
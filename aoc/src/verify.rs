@@ -0,0 +1,146 @@
+use aoc_core::Answer;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// An answer as written in a TOML file, either a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum AcceptedAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl AcceptedAnswer {
+    fn into_string(self) -> String {
+        match self {
+            AcceptedAnswer::Number(number) => number.to_string(),
+            AcceptedAnswer::Text(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    #[serde(rename = "part-1")]
+    part_1: Option<AcceptedAnswer>,
+    #[serde(rename = "part-2")]
+    part_2: Option<AcceptedAnswer>,
+}
+
+/// The accepted answers of the real puzzle inputs, kept in `answers.toml` in
+/// the input directory under `[day-<N>]` tables with `part-1` and `part-2`
+/// keys.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AcceptedAnswers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl AcceptedAnswers {
+    pub fn path() -> PathBuf {
+        aoc_core::input_dir().join(ANSWERS_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        Self::parse(&contents).map_err(|error| format!("invalid {}: {}", path.display(), error))
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let days: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(|error| error.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (name, day_answers) in days {
+            let day = name
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or(format!("expected a `[day-<N>]` table, found `[{}]`", name))?;
+            for (part, answer) in [(1, day_answers.part_1), (2, day_answers.part_2)] {
+                if let Some(answer) = answer {
+                    answers.insert((day, part), answer.into_string());
+                }
+            }
+        }
+        Ok(AcceptedAnswers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch,
+    /// There is no accepted answer to compare with.
+    Unrecorded,
+}
+
+pub fn check(accepted: Option<&str>, answer: &Answer) -> Outcome {
+    match accepted {
+        Some(accepted) if accepted == answer.to_string() => Outcome::Match,
+        Some(_) => Outcome::Mismatch,
+        None => Outcome::Unrecorded,
+    }
+}
+
+/// Shows the accepted answer and the new one line by line, marking the lines
+/// that differ.
+pub fn diff(accepted: &str, answer: &Answer) -> String {
+    let answer = answer.to_string();
+    let accepted_lines = accepted.lines().collect::<Vec<_>>();
+    let answer_lines = answer.lines().collect::<Vec<_>>();
+    let mut diff = Vec::new();
+    for index in 0..accepted_lines.len().max(answer_lines.len()) {
+        match (accepted_lines.get(index), answer_lines.get(index)) {
+            (Some(accepted), Some(answer)) if accepted == answer => {
+                diff.push(format!("  {}", accepted))
+            }
+            (accepted, answer) => {
+                diff.extend(accepted.map(|accepted| format!("- {}", accepted)));
+                diff.extend(answer.map(|answer| format!("+ {}", answer)));
+            }
+        }
+    }
+    diff.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = AcceptedAnswers::parse(
+            "[day-5]
+part-1 = 35
+part-2 = 46
+
+[day-10]
+part-2 = \"EHZRJFAB\"",
+        )
+        .unwrap();
+        assert_eq!(answers.get(5, 1), Some("35"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some("EHZRJFAB"));
+        assert!(AcceptedAnswers::parse("[five]\npart-1 = 35").is_err());
+        assert!(AcceptedAnswers::parse("[day-5]\npart-3 = 35").is_err());
+    }
+
+    #[test]
+    fn test_check_and_diff() {
+        assert_eq!(check(Some("46"), &Answer::Number(46)), Outcome::Match);
+        assert_eq!(check(Some("46"), &Answer::Number(47)), Outcome::Mismatch);
+        assert_eq!(check(None, &Answer::Unsolved), Outcome::Unrecorded);
+        assert_eq!(diff("46", &Answer::Number(47)), "- 46\n+ 47");
+        assert_eq!(
+            diff("#..#\n####", &Answer::from("#..#\n#..#")),
+            "  #..#\n- ####\n+ #..#"
+        );
+    }
+}
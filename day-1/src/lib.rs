@@ -1,3 +1,4 @@
+use aoc_core::{Answer, ParseError, Solution};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -5,10 +6,6 @@ const NUMBERS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn read_file(path: &str) -> String {
-    std::fs::read_to_string(path).expect("Something went wrong reading the file")
-}
-
 fn sum_first_and_last_digit(line: &str) -> u32 {
     let digits = line
         .chars()
@@ -75,17 +72,33 @@ fn convert_number_as_text_to_number_as_string(line: &str) -> String {
     line_with_parsed_numbers
 }
 
-fn sum_all_the_lines(input_data: &str) -> u32 {
+fn sum_all_the_lines(lines: &[String]) -> u32 {
     let mut sum = 0;
-    for line in input_data.lines().filter(|x| !x.is_empty()) {
+    for line in lines {
         let line_with_parsed_numbers = convert_number_as_text_to_number_as_string(line);
         sum += sum_first_and_last_digit(&line_with_parsed_numbers);
     }
     sum
 }
 
-fn main() {
-    println!("{}", sum_all_the_lines(read_file("src/input.txt").as_str()));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(aoc_core::lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        sum_all_the_lines(lines).into()
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +115,6 @@ mod tests {
         zoneight234
         7pqrstsixteen";
 
-        assert_eq!(sum_all_the_lines(input), 281);
+        assert_eq!(sum_all_the_lines(&Puzzle::parse(input).unwrap()), 281);
     }
 }
@@ -0,0 +1,186 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+
+const RED_CUBE_LIMIT: u8 = 12;
+const GREEN_CUBE_LIMIT: u8 = 13;
+const BLUE_CUBE_LIMIT: u8 = 14;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u16,
+    rounds: Vec<Round>,
+}
+
+#[derive(Debug, Default)]
+struct Round {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+fn parse_round(line: &Line, round: &str) -> Result<Round, ParseError> {
+    let mut parsed_round = Round::default();
+    for cubes in round.split(", ") {
+        let (count, color) = cubes
+            .split_once(' ')
+            .ok_or_else(|| line.error(cubes, "cubes in the form `<count> <color>`"))?;
+        let count = line.parse::<u8>(count, "a cube count")?;
+        match color {
+            "red" => parsed_round.red = count,
+            "green" => parsed_round.green = count,
+            "blue" => parsed_round.blue = count,
+            _ => return Err(line.error(color, "`red`, `green` or `blue`")),
+        }
+    }
+    Ok(parsed_round)
+}
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+    let (game_id, rounds) = line
+        .text
+        .split_once(": ")
+        .ok_or_else(|| line.missing("`: ` between the game id and its rounds"))?;
+    let game_id = game_id
+        .strip_prefix("Game ")
+        .ok_or_else(|| line.error(game_id, "`Game <id>`"))?;
+    Ok(Game {
+        id: line.parse::<u16>(game_id, "a game id")?,
+        rounds: rounds
+            .split("; ")
+            .map(|round| parse_round(&line, round))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn filter_possible_games(
+    games: &[Game],
+    red_cube_count: u8,
+    green_cube_count: u8,
+    blue_cube_count: u8,
+) -> Vec<u16> {
+    games
+        .iter()
+        .filter_map(|game| {
+            if game.rounds.iter().any(|round| {
+                round.red > red_cube_count
+                    || round.green > green_cube_count
+                    || round.blue > blue_cube_count
+            }) {
+                return None;
+            }
+            Some(game.id)
+        })
+        .collect()
+}
+
+fn sum_id_of_possible_games(game_ids: Vec<u16>) -> u32 {
+    game_ids.iter().map(|&id| id as u32).sum()
+}
+
+fn calculate_power_of_game(game: &Game) -> u32 {
+    let max_number_of_red_cubes = game
+        .rounds
+        .iter()
+        .map(|round| round.red)
+        .max()
+        .unwrap_or(0u8);
+    let max_number_of_green_cubes = game
+        .rounds
+        .iter()
+        .map(|round| round.green)
+        .max()
+        .unwrap_or(0u8);
+    let max_number_of_blue_cubes = game
+        .rounds
+        .iter()
+        .map(|round| round.blue)
+        .max()
+        .unwrap_or(0u8);
+    max_number_of_red_cubes as u32
+        * max_number_of_green_cubes as u32
+        * max_number_of_blue_cubes as u32
+}
+
+fn sum_power_of_games(games: &[Game]) -> u64 {
+    let power_of_each_game = games
+        .iter()
+        .map(calculate_power_of_game)
+        .collect::<Vec<_>>();
+    power_of_each_game.iter().map(|x| *x as u64).sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input).map(parse_game).collect()
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        sum_id_of_possible_games(filter_possible_games(
+            games,
+            RED_CUBE_LIMIT,
+            GREEN_CUBE_LIMIT,
+            BLUE_CUBE_LIMIT,
+        ))
+        .into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        sum_power_of_games(games).into()
+    }
+}
+
+#[test]
+fn test_part_1() {
+    let example = "
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+    assert_eq!(
+        sum_id_of_possible_games(filter_possible_games(
+            &Puzzle::parse(example).unwrap(),
+            RED_CUBE_LIMIT,
+            GREEN_CUBE_LIMIT,
+            BLUE_CUBE_LIMIT
+        )),
+        8
+    );
+}
+
+#[test]
+fn test_part_2() {
+    let example = "
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+    assert_eq!(sum_power_of_games(&Puzzle::parse(example).unwrap()), 2286);
+}
+
+#[test]
+fn test_parse_error() {
+    let example = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+    let error = Puzzle::parse(example).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 19));
+    assert_eq!(error.text, "purple");
+}
+
+#[test]
+fn test_largest_counts() {
+    let example = "Game 65535: 255 red, 255 green, 255 blue
+Game 65535: 255 red, 255 green, 255 blue";
+    let games = Puzzle::parse(example).unwrap();
+    assert_eq!(sum_power_of_games(&games), 2 * 255u64.pow(3));
+    assert_eq!(
+        sum_id_of_possible_games(games.iter().map(|game| game.id).collect()),
+        2 * 65535
+    );
+}
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+
 #[derive(Debug)]
 struct NumberInLine {
     number: usize,
@@ -15,10 +17,6 @@ impl Clone for NumberInLine {
     }
 }
 
-fn read_input_file() -> String {
-    std::fs::read_to_string("input.txt").unwrap()
-}
-
 fn get_index_of_symbol((i, c): (usize, char)) -> Option<usize> {
     if c != '.' && !c.is_ascii_alphanumeric() {
         Some(i)
@@ -60,10 +58,26 @@ fn find_numbers_in_line(line: &str) -> Vec<NumberInLine> {
     )
 }
 
-fn sum_part_numbers(input: &str) -> usize {
-    input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
+fn validate_schematic_line(line: &Line) -> Result<(), ParseError> {
+    if let Some((index, c)) = line.text.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(line.error(
+            &line.text[index..index + c.len_utf8()],
+            "an ASCII character",
+        ));
+    }
+    for number in line
+        .text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|number| !number.is_empty())
+    {
+        line.parse::<usize>(number, "a part number that fits in a usize")?;
+    }
+    Ok(())
+}
+
+fn sum_part_numbers(schematic: &[String]) -> usize {
+    schematic
+        .iter()
         .enumerate()
         .filter_map(|(line_index, line)| {
             let numbers_in_line = find_numbers_in_line(line);
@@ -72,9 +86,9 @@ fn sum_part_numbers(input: &str) -> usize {
                 return None;
             }
 
-            let symbol_indices_in_prev_line = input
-                .lines()
-                .nth(line_index.checked_sub(1).unwrap_or_default())
+            let symbol_indices_in_prev_line = schematic
+                .get(line_index.saturating_sub(1))
+                .map(String::as_str)
                 .unwrap_or_default()
                 .chars()
                 .enumerate()
@@ -87,9 +101,9 @@ fn sum_part_numbers(input: &str) -> usize {
                 .filter_map(get_index_of_symbol)
                 .collect::<Vec<usize>>();
 
-            let symbol_indices_of_next_line = input
-                .lines()
-                .nth(line_index.checked_add(1).unwrap_or_default())
+            let symbol_indices_of_next_line = schematic
+                .get(line_index + 1)
+                .map(String::as_str)
                 .unwrap_or_default()
                 .chars()
                 .enumerate()
@@ -132,10 +146,9 @@ fn sum_part_numbers(input: &str) -> usize {
         .sum()
 }
 
-fn sum_gear_ratios(input: &str) -> usize {
-    input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
+fn sum_gear_ratios(schematic: &[String]) -> usize {
+    schematic
+        .iter()
         .enumerate()
         .filter_map(|(line_index, line)| {
             let asterisk_positions_in_current_line: Vec<usize> = line
@@ -155,16 +168,16 @@ fn sum_gear_ratios(input: &str) -> usize {
             }
 
             let numbers_in_previous_line = find_numbers_in_line(
-                input
-                    .lines()
-                    .nth(line_index.checked_sub(1).unwrap_or_default())
+                schematic
+                    .get(line_index.saturating_sub(1))
+                    .map(String::as_str)
                     .unwrap_or_default(),
             );
             let numbers_in_current_line = find_numbers_in_line(line);
             let numbers_in_next_line = find_numbers_in_line(
-                input
-                    .lines()
-                    .nth(line_index.checked_add(1).unwrap_or_default())
+                schematic
+                    .get(line_index + 1)
+                    .map(String::as_str)
                     .unwrap_or_default(),
             );
             let numbers = [
@@ -182,7 +195,7 @@ fn sum_gear_ratios(input: &str) -> usize {
                 0usize,
                 |sum_of_gear_ratios_in_line, asterisk_index| {
                     let numbers_next_to_asterisk = numbers.iter().filter_map(|number| {
-                        if number.start_index.checked_sub(1).unwrap_or_default() <= *asterisk_index
+                        if number.start_index.saturating_sub(1) <= *asterisk_index
                             && *asterisk_index
                                 <= number.end_index.checked_add(1).unwrap_or_default()
                         {
@@ -203,9 +216,27 @@ fn sum_gear_ratios(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    println!("Part 1: {}", sum_part_numbers(&read_input_file()));
-    println!("Part 2: {}", sum_gear_ratios(&read_input_file()));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input)
+            .map(|line| {
+                validate_schematic_line(&line)?;
+                Ok(line.text.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(schematic: &Self::Input) -> Answer {
+        sum_part_numbers(schematic).into()
+    }
+
+    fn part2(schematic: &Self::Input) -> Answer {
+        sum_gear_ratios(schematic).into()
+    }
 }
 
 #[cfg(test)]
@@ -224,7 +255,7 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!(sum_part_numbers(input), 4361);
+        assert_eq!(sum_part_numbers(&Puzzle::parse(input).unwrap()), 4361);
     }
 
     #[test]
@@ -239,6 +270,6 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!(sum_gear_ratios(input), 467835);
+        assert_eq!(sum_gear_ratios(&Puzzle::parse(input).unwrap()), 467835);
     }
 }
@@ -0,0 +1,91 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Scratchcard {
+    winning_numbers: Vec<usize>,
+    played_numbers: Vec<usize>,
+}
+
+fn parse_scratchcard(line: Line) -> Result<Scratchcard, ParseError> {
+    // split lines by :
+    let (_, card_values) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.missing("`:` after the card id"))?;
+    // split result by |
+    // [0] is winning numbers
+    // [1] is played numbers
+    let [winning_numbers_as_string, played_numbers_as_string] =
+        match card_values.split('|').collect::<Vec<&str>>()[..] {
+            [winning_numbers_as_string, played_numbers_as_string] => {
+                [winning_numbers_as_string, played_numbers_as_string]
+            }
+            _ => {
+                return Err(line.error(
+                    card_values,
+                    "winning and played numbers separated by a single `|`",
+                ))
+            }
+        };
+    // split both by space
+    Ok(Scratchcard {
+        winning_numbers: line.parse_numbers(winning_numbers_as_string)?,
+        played_numbers: line.parse_numbers(played_numbers_as_string)?,
+    })
+}
+
+fn sum_scratchcard_points(scratchcards: &[Scratchcard]) -> usize {
+    scratchcards
+        .iter()
+        .map(|scratchcard| {
+            let matching_number_count = scratchcard
+                .played_numbers
+                .iter()
+                .filter(|x| scratchcard.winning_numbers.contains(x))
+                .count();
+            // return 2**(matches - 1)
+            match matching_number_count {
+                0 => 0,
+                _ => 2usize.pow(matching_number_count.saturating_sub(1).try_into().unwrap()),
+            }
+        })
+        // sum
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Scratchcard>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input).map(parse_scratchcard).collect()
+    }
+
+    fn part1(scratchcards: &Self::Input) -> Answer {
+        sum_scratchcard_points(scratchcards).into()
+    }
+
+    fn part2(_scratchcards: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    #[test]
+    fn it_works() {
+        let example_input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(
+            super::sum_scratchcard_points(&super::Puzzle::parse(example_input).unwrap()),
+            13
+        );
+    }
+}
@@ -0,0 +1,1033 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+
+#[derive(Debug, PartialEq)]
+struct AlmanacMapRange {
+    destination_range_start: usize,
+    source_range_start: usize,
+    range_length: usize,
+    /// Line of the almanac the range is defined on.
+    line: usize,
+}
+
+impl AlmanacMapRange {
+    fn source_range_end(&self) -> Option<usize> {
+        self.source_range_start.checked_add(self.range_length)
+    }
+
+    fn destination_range_end(&self) -> Option<usize> {
+        self.destination_range_start.checked_add(self.range_length)
+    }
+}
+
+/// A section of the almanac converting numbers of one category into numbers
+/// of another.
+#[derive(Debug, PartialEq)]
+struct AlmanacMap {
+    source: String,
+    destination: String,
+    ranges: Vec<AlmanacMapRange>,
+    /// Line of the almanac with the map's header.
+    line: usize,
+}
+
+/// The seeds and the maps between categories. Every category has at most one
+/// map to another category, and the maps lead from `seed` to `location`
+/// without cycles.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    /// Line of the almanac with the seeds.
+    seeds_line: usize,
+    maps: Vec<AlmanacMap>,
+}
+
+fn get_seeds_from_almanac(line: &Line) -> Result<Vec<usize>, ParseError> {
+    let seeds = line
+        .text
+        .strip_prefix("seeds:")
+        .ok_or_else(|| line.error(line.text, "a `seeds: ` line"))?;
+    line.parse_numbers(seeds)
+}
+
+fn get_seed_ranges_from_almanac(almanac: &Almanac) -> Vec<std::ops::Range<usize>> {
+    almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|chunk| {
+            let start = chunk[0];
+            let length = chunk[1];
+            start..(start + length)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Reads the source and destination categories from a `<source>-to-<destination>
+/// map:` header.
+fn parse_map_header<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    line.text
+        .trim_end()
+        .strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .filter(|(source, destination)| {
+            [source, destination]
+                .iter()
+                .all(|category| !category.is_empty() && !category.contains(char::is_whitespace))
+        })
+        .ok_or_else(|| line.error(line.text, "a map header like `seed-to-soil map:`"))
+}
+
+/// Parses the map sections and checks that they form a chain from `seed` to
+/// `location`: no category may be converted twice, and no maps may go round
+/// in a cycle.
+fn parse_maps_from_almanac(
+    almanac: &str,
+    sections: &[Vec<Line>],
+) -> Result<Vec<AlmanacMap>, ParseError> {
+    let mut maps: Vec<AlmanacMap> = Vec::new();
+    let mut headers: Vec<Line> = Vec::new();
+    for section in sections {
+        let header = &section[0];
+        let (source, destination) = parse_map_header(header)?;
+        if let Some((previous, map)) = headers
+            .iter()
+            .zip(&maps)
+            .find(|(_, map)| map.source == source)
+        {
+            let expected = if map.destination == destination {
+                format!("a map that is not a duplicate of line {}", previous.number)
+            } else {
+                format!(
+                    "a single map from `{}`, which line {} already converts to `{}`",
+                    source, previous.number, map.destination
+                )
+            };
+            return Err(header.error(header.text.trim_end(), expected));
+        }
+        maps.push(AlmanacMap {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            ranges: map_section_to_almanac_ranges(&section[1..])?,
+            line: header.number,
+        });
+        headers.push(*header);
+    }
+    for (header, map) in headers.iter().zip(&maps) {
+        let mut category = map.destination.as_str();
+        for _ in 0..maps.len() {
+            if category == map.source {
+                return Err(header.error(
+                    header.text.trim_end(),
+                    format!(
+                        "maps without a cycle, but `{}` converts back to itself",
+                        category
+                    ),
+                ));
+            }
+            match maps.iter().find(|next| next.source == category) {
+                Some(next) => category = &next.destination,
+                None => break,
+            }
+        }
+    }
+    let mut category = "seed";
+    while category != "location" {
+        category = &maps
+            .iter()
+            .find(|map| map.source == category)
+            .ok_or_else(|| {
+                ParseError::end_of_input(
+                    almanac,
+                    format!(
+                        "a `{}-to-<category> map:` section leading to `location`",
+                        category
+                    ),
+                )
+            })?
+            .destination;
+    }
+    Ok(maps)
+}
+
+fn map_section_to_almanac_ranges(
+    almanac_section: &[Line],
+) -> Result<Vec<AlmanacMapRange>, ParseError> {
+    almanac_section
+        .iter()
+        .map(|line| {
+            let [destination_range_start, source_range_start, range_length]: [usize; 3] = line
+                .parse_numbers(line.text)?
+                .try_into()
+                .map_err(|_| {
+                    line.error(
+                        line.text,
+                        "three numbers: destination range start, source range start and range length",
+                    )
+                })?;
+            Ok(AlmanacMapRange {
+                destination_range_start,
+                source_range_start,
+                range_length,
+                line: line.number,
+            })
+        })
+        .collect()
+}
+
+/// The first range of the map that covers `number`.
+fn find_active_range(number: usize, map: &[AlmanacMapRange]) -> Option<&AlmanacMapRange> {
+    map.iter().find(|map_range| {
+        number >= map_range.source_range_start
+            && number < map_range.source_range_start + map_range.range_length
+    })
+}
+
+fn map_number_according_to_map(number: usize, map: &[AlmanacMapRange]) -> usize {
+    if let Some(active_range) = find_active_range(number, map) {
+        return active_range.destination_range_start + number - active_range.source_range_start;
+    }
+    number
+}
+
+/// A piece of a [`PiecewiseMap`], sending `source` to the range of the same
+/// length starting at `destination_start`.
+#[derive(Debug, Clone, PartialEq)]
+struct Piece {
+    source: std::ops::Range<usize>,
+    destination_start: usize,
+}
+
+impl Piece {
+    fn map(&self, number: usize) -> usize {
+        self.destination_start + number - self.source.start
+    }
+
+    fn is_identity(&self) -> bool {
+        self.source.start == self.destination_start
+    }
+}
+
+/// A map from numbers to numbers made of sorted, non-overlapping pieces that
+/// cover every number, so that a number is mapped with a binary search. Maps
+/// of consecutive categories compose into a single one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    /// Builds the map of an almanac section. Numbers outside of every range
+    /// map to themselves, and where ranges overlap the first one wins, like
+    /// in [`map_number_according_to_map`].
+    fn from_ranges(map: &[AlmanacMapRange]) -> Self {
+        let mut boundaries = map
+            .iter()
+            .flat_map(|map_range| {
+                [
+                    map_range.source_range_start,
+                    map_range.source_range_start + map_range.range_length,
+                ]
+            })
+            .chain([0, usize::MAX])
+            .collect::<Vec<_>>();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let pieces = boundaries
+            .windows(2)
+            .map(|window| Piece {
+                source: window[0]..window[1],
+                destination_start: map_number_according_to_map(window[0], map),
+            })
+            .collect();
+        PiecewiseMap { pieces }.merged()
+    }
+
+    /// Joins neighbouring pieces that continue each other.
+    fn merged(self) -> Self {
+        let mut pieces: Vec<Piece> = Vec::with_capacity(self.pieces.len());
+        for piece in self.pieces {
+            match pieces.last_mut() {
+                Some(last)
+                    if last.map(last.source.end - 1).checked_add(1)
+                        == Some(piece.destination_start) =>
+                {
+                    last.source.end = piece.source.end
+                }
+                _ => pieces.push(piece),
+            }
+        }
+        PiecewiseMap { pieces }
+    }
+
+    pub fn get(&self, number: usize) -> usize {
+        let index = self
+            .pieces
+            .partition_point(|piece| piece.source.end <= number);
+        match self.pieces.get(index) {
+            Some(piece) if piece.source.contains(&number) => piece.map(number),
+            _ => number,
+        }
+    }
+
+    /// Finds the numbers that map into `range`, as sorted and disjoint ranges.
+    pub fn preimage(&self, range: std::ops::Range<usize>) -> Vec<std::ops::Range<usize>> {
+        let mut preimage: Vec<std::ops::Range<usize>> = Vec::new();
+        for piece in &self.pieces {
+            let destination_end = piece.destination_start.saturating_add(piece.source.len());
+            let start = range.start.max(piece.destination_start);
+            let end = range.end.min(destination_end);
+            if start >= end {
+                continue;
+            }
+            let source = piece.source.start + (start - piece.destination_start)
+                ..piece.source.start + (end - piece.destination_start);
+            match preimage.last_mut() {
+                Some(last) if last.end == source.start => last.end = source.end,
+                _ => preimage.push(source),
+            }
+        }
+        preimage
+    }
+
+    /// Composes the map with `next`, which maps the numbers this one maps to.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = Vec::new();
+        for piece in &self.pieces {
+            let mut start = piece.source.start;
+            while start < piece.source.end {
+                let destination = piece.map(start);
+                let next_index = next
+                    .pieces
+                    .partition_point(|next_piece| next_piece.source.end <= destination);
+                let (destination_start, length) = match next.pieces.get(next_index) {
+                    Some(next_piece) if next_piece.source.contains(&destination) => (
+                        next_piece.map(destination),
+                        next_piece.source.end - destination,
+                    ),
+                    _ => (destination, usize::MAX - destination),
+                };
+                let end = piece.source.end.min(start.saturating_add(length));
+                pieces.push(Piece {
+                    source: start..end,
+                    destination_start,
+                });
+                start = end;
+            }
+        }
+        PiecewiseMap { pieces }.merged()
+    }
+}
+
+/// Writes the pieces that do not map numbers to themselves as almanac lines,
+/// `<destination range start> <source range start> <range length>`.
+impl std::fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for piece in self.pieces.iter().filter(|piece| !piece.is_identity()) {
+            writeln!(
+                f,
+                "{} {} {}",
+                piece.destination_start,
+                piece.source.start,
+                piece.source.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn compose(chain: &[&AlmanacMap]) -> PiecewiseMap {
+    chain
+        .iter()
+        .fold(PiecewiseMap::from_ranges(&[]), |map, next| {
+            map.then(&PiecewiseMap::from_ranges(&next.ranges))
+        })
+}
+
+fn find_lowest_location_number(almanac: &Almanac, seeds: &[usize]) -> usize {
+    let chain = almanac.seed_to_location_chain();
+    seeds
+        .iter()
+        .map(|seed| {
+            chain.iter().fold(*seed, |number, map| {
+                map_number_according_to_map(number, &map.ranges)
+            })
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+/// Maps whole ranges of numbers, splitting them where they cross the
+/// boundaries of the map's ranges. Numbers outside of every map range are
+/// passed through unchanged.
+fn map_ranges_according_to_map(
+    ranges: &[std::ops::Range<usize>],
+    map: &[AlmanacMapRange],
+) -> Vec<std::ops::Range<usize>> {
+    trace_ranges_through_map(ranges, map)
+        .into_iter()
+        .map(|step| step.destination)
+        .collect()
+}
+
+/// Like [`map_ranges_according_to_map`], but keeps where every piece came
+/// from and which line of the map moved it.
+fn trace_ranges_through_map(
+    ranges: &[std::ops::Range<usize>],
+    map: &[AlmanacMapRange],
+) -> Vec<RangeStep> {
+    let mut mapped_ranges = Vec::new();
+    let mut unmapped_ranges = ranges.to_vec();
+    for map_range in map {
+        let source_range_end = map_range.source_range_start + map_range.range_length;
+        let mut remaining_ranges = Vec::new();
+        for range in unmapped_ranges {
+            let overlap_start = range.start.max(map_range.source_range_start);
+            let overlap_end = range.end.min(source_range_end);
+            if overlap_start >= overlap_end {
+                remaining_ranges.push(range);
+                continue;
+            }
+            mapped_ranges.push(RangeStep {
+                source: overlap_start..overlap_end,
+                destination: map_range.destination_range_start + overlap_start
+                    - map_range.source_range_start
+                    ..map_range.destination_range_start + overlap_end
+                        - map_range.source_range_start,
+                line: Some(map_range.line),
+            });
+            if range.start < overlap_start {
+                remaining_ranges.push(range.start..overlap_start);
+            }
+            if overlap_end < range.end {
+                remaining_ranges.push(overlap_end..range.end);
+            }
+        }
+        unmapped_ranges = remaining_ranges;
+    }
+    mapped_ranges.extend(unmapped_ranges.into_iter().map(|range| RangeStep {
+        source: range.clone(),
+        destination: range,
+        line: None,
+    }));
+    mapped_ranges
+}
+
+/// Where a number ends up in one category on its way from seed to location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub category: String,
+    pub number: usize,
+    /// Line of the range that converted the number, or `None` when no range
+    /// covered it and it kept its value.
+    pub line: Option<usize>,
+}
+
+/// A piece of a range of numbers converted into the next category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeStep {
+    pub source: std::ops::Range<usize>,
+    pub destination: std::ops::Range<usize>,
+    /// Line of the range that converted the piece, or `None` when no range
+    /// covered it and it kept its values.
+    pub line: Option<usize>,
+}
+
+fn find_lowest_location_in_ranges(
+    almanac: &Almanac,
+    seed_ranges: &[std::ops::Range<usize>],
+) -> usize {
+    almanac
+        .seed_to_location_chain()
+        .iter()
+        .fold(seed_ranges.to_vec(), |ranges, map| {
+            map_ranges_according_to_map(&ranges, &map.ranges)
+        })
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .unwrap_or(0)
+}
+
+/// Something dubious about an almanac.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// The source ranges of two lines of the same map overlap, and the
+    /// earlier line wins.
+    Overlap {
+        map: String,
+        line: usize,
+        earlier_line: usize,
+        numbers: std::ops::Range<usize>,
+    },
+    /// A range, or a seed range, ends past the largest number of a `usize`.
+    Overflow { map: String, line: usize },
+    /// Numbers below the end of a map's last source range that no range
+    /// covers, which map to themselves.
+    Gap {
+        map: String,
+        line: usize,
+        numbers: std::ops::Range<usize>,
+    },
+    /// An odd number of seeds, whose last one is left out when the seeds are
+    /// read as ranges.
+    OddSeeds { line: usize, count: usize },
+}
+
+impl Finding {
+    pub fn line(&self) -> usize {
+        match self {
+            Finding::Overlap { line, .. }
+            | Finding::Overflow { line, .. }
+            | Finding::Gap { line, .. }
+            | Finding::OddSeeds { line, .. } => *line,
+        }
+    }
+
+    /// Whether the finding points at a likely mistake, rather than at a part
+    /// of the almanac that is merely worth knowing about.
+    pub fn is_problem(&self) -> bool {
+        !matches!(self, Finding::Gap { .. })
+    }
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Finding::Overlap {
+                map,
+                line,
+                earlier_line,
+                numbers,
+            } => write!(
+                f,
+                "line {}: {} range overlaps line {} on {:?}, where line {} wins",
+                line, map, earlier_line, numbers, earlier_line
+            ),
+            Finding::Overflow { map, line } => write!(
+                f,
+                "line {}: {} range ends past the largest number, {}",
+                line,
+                map,
+                usize::MAX
+            ),
+            Finding::Gap { map, line, numbers } => {
+                write!(f, "line {}: {} maps {:?} to themselves", line, map, numbers)
+            }
+            Finding::OddSeeds { line, count } => write!(
+                f,
+                "line {}: {} seeds do not pair up into ranges, the last one is left out",
+                line, count
+            ),
+        }
+    }
+}
+
+impl Almanac {
+    /// Parses an almanac without rejecting ranges that overflow, so that
+    /// [`Almanac::check`] can report them. Converting numbers with such an
+    /// almanac may overflow.
+    pub fn parse_unchecked(almanac: &str) -> Result<Self, ParseError> {
+        let sections = aoc_core::sections(almanac);
+        let Some((seeds, maps)) = sections.split_first() else {
+            return Err(ParseError::end_of_input(almanac, "a `seeds: ` line"));
+        };
+        if let Some(line) = seeds.get(1) {
+            return Err(line.error(
+                line.text,
+                "a blank line between the seeds and the first map",
+            ));
+        }
+        Ok(Almanac {
+            seeds: get_seeds_from_almanac(&seeds[0])?,
+            seeds_line: seeds[0].number,
+            maps: parse_maps_from_almanac(almanac, maps)?,
+        })
+    }
+
+    /// The maps leading from the `source` category to the `destination`
+    /// category, in order, if there is a path between them.
+    fn chain(&self, source: &str, destination: &str) -> Option<Vec<&AlmanacMap>> {
+        let mut chain = Vec::new();
+        let mut category = source;
+        while category != destination {
+            let map = self.maps.iter().find(|map| map.source == category)?;
+            chain.push(map);
+            category = &map.destination;
+        }
+        Some(chain)
+    }
+
+    /// The maps leading from `seed` to `location`, which parsing guarantees.
+    fn seed_to_location_chain(&self) -> Vec<&AlmanacMap> {
+        self.chain("seed", "location")
+            .expect("the maps are checked to lead from seed to location while parsing")
+    }
+
+    /// Composes the maps converting the `source` category into the
+    /// `destination` category, if there is a path between them.
+    pub fn conversion(&self, source: &str, destination: &str) -> Option<PiecewiseMap> {
+        Some(compose(&self.chain(source, destination)?))
+    }
+
+    /// The conversion named like `seed-to-soil`, which may skip categories
+    /// like `soil-to-light`.
+    pub fn map(&self, name: &str) -> Option<PiecewiseMap> {
+        let (source, destination) = name.split_once("-to-")?;
+        self.conversion(source, destination)
+    }
+
+    /// Composes the maps into a single map from seed to location.
+    pub fn seed_to_location_map(&self) -> PiecewiseMap {
+        compose(&self.seed_to_location_chain())
+    }
+
+    /// Lists the overlapping, overflowing and uncovered ranges of the maps
+    /// and an odd number of seeds, in the order of the almanac's lines.
+    pub fn check(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        if self.seeds.len() % 2 == 1 {
+            findings.push(Finding::OddSeeds {
+                line: self.seeds_line,
+                count: self.seeds.len(),
+            });
+        }
+        if self
+            .seeds
+            .chunks_exact(2)
+            .any(|chunk| chunk[0].checked_add(chunk[1]).is_none())
+        {
+            findings.push(Finding::Overflow {
+                map: "seeds".to_string(),
+                line: self.seeds_line,
+            });
+        }
+        for map in &self.maps {
+            let name = format!("{}-to-{}", map.source, map.destination);
+            let mut covered: Vec<std::ops::Range<usize>> = Vec::new();
+            for (index, range) in map.ranges.iter().enumerate() {
+                let (Some(source_range_end), Some(_)) =
+                    (range.source_range_end(), range.destination_range_end())
+                else {
+                    findings.push(Finding::Overflow {
+                        map: name.clone(),
+                        line: range.line,
+                    });
+                    continue;
+                };
+                for earlier in &map.ranges[..index] {
+                    let start = range.source_range_start.max(earlier.source_range_start);
+                    let end =
+                        source_range_end.min(earlier.source_range_end().unwrap_or(usize::MAX));
+                    if start < end {
+                        findings.push(Finding::Overlap {
+                            map: name.clone(),
+                            line: range.line,
+                            earlier_line: earlier.line,
+                            numbers: start..end,
+                        });
+                    }
+                }
+                covered.push(range.source_range_start..source_range_end);
+            }
+            covered.sort_by_key(|range| range.start);
+            let mut covered_end = 0;
+            for range in covered {
+                if covered_end < range.start {
+                    findings.push(Finding::Gap {
+                        map: name.clone(),
+                        line: map.line,
+                        numbers: covered_end..range.start,
+                    });
+                }
+                covered_end = covered_end.max(range.end);
+            }
+        }
+        findings.sort_by_key(Finding::line);
+        findings
+    }
+
+    /// Follows a seed through every category up to its location.
+    pub fn trace(&self, seed: usize) -> Vec<Step> {
+        let mut number = seed;
+        self.seed_to_location_chain()
+            .iter()
+            .map(|map| {
+                let active_range = find_active_range(number, &map.ranges);
+                number = map_number_according_to_map(number, &map.ranges);
+                Step {
+                    category: map.destination.clone(),
+                    number,
+                    line: active_range.map(|active_range| active_range.line),
+                }
+            })
+            .collect()
+    }
+
+    /// Follows a range of seeds through every category up to the locations,
+    /// returning each category with the pieces the range was split into,
+    /// sorted by where they came from.
+    pub fn trace_ranges(&self, seeds: std::ops::Range<usize>) -> Vec<(String, Vec<RangeStep>)> {
+        let mut ranges = vec![seeds];
+        self.seed_to_location_chain()
+            .iter()
+            .map(|map| {
+                let mut steps = trace_ranges_through_map(&ranges, &map.ranges);
+                steps.sort_by_key(|step| step.source.start);
+                ranges = steps.iter().map(|step| step.destination.clone()).collect();
+                (map.destination.clone(), steps)
+            })
+            .collect()
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let almanac = Almanac::parse_unchecked(input)?;
+        if let Some(finding) = almanac
+            .check()
+            .into_iter()
+            .find(|finding| matches!(finding, Finding::Overflow { .. }))
+        {
+            let line = aoc_core::lines(input)
+                .find(|line| line.number == finding.line())
+                .expect("findings point at lines of the almanac");
+            return Err(line.error(line.text, "a range that ends within the numbers of a usize"));
+        }
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        find_lowest_location_number(almanac, &almanac.seeds).into()
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        let seed_ranges = get_seed_ranges_from_almanac(almanac);
+        find_lowest_location_in_ranges(almanac, &seed_ranges).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ALMANAC: &str = "
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_find_lowest_location_number() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        assert_eq!(find_lowest_location_number(&almanac, &almanac.seeds), 35);
+    }
+
+    #[test]
+    fn test_find_lowest_location_in_ranges() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        let seed_ranges = get_seed_ranges_from_almanac(&almanac);
+        assert_eq!(find_lowest_location_in_ranges(&almanac, &seed_ranges), 46);
+    }
+
+    #[test]
+    fn test_piecewise_map() {
+        let seed_to_soil = PiecewiseMap::from_ranges(&[
+            AlmanacMapRange {
+                destination_range_start: 50,
+                source_range_start: 98,
+                range_length: 2,
+                line: 0,
+            },
+            AlmanacMapRange {
+                destination_range_start: 52,
+                source_range_start: 50,
+                range_length: 48,
+                line: 0,
+            },
+        ]);
+        let soil_to_fertilizer = PiecewiseMap::from_ranges(&[AlmanacMapRange {
+            destination_range_start: 0,
+            source_range_start: 50,
+            range_length: 10,
+            line: 0,
+        }]);
+        let seed_to_fertilizer = seed_to_soil.then(&soil_to_fertilizer);
+        assert_eq!(seed_to_fertilizer.to_string(), "2 50 8\n60 58 40\n0 98 2\n");
+        assert_eq!(seed_to_fertilizer.get(51), 3);
+        assert_eq!(seed_to_fertilizer.get(98), 0);
+        assert_eq!(seed_to_fertilizer.get(100), 100);
+        assert_eq!(seed_to_fertilizer.get(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_seed_to_location_map_matches_chained_maps() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        let seed_to_location = almanac.seed_to_location_map();
+        // A xorshift generator, seeded so that failures can be reproduced.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let seed = (state % 200) as usize;
+            assert_eq!(
+                seed_to_location.get(seed),
+                find_lowest_location_number(&almanac, &[seed]),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_preimage_round_trips() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        let seed_to_location = almanac.seed_to_location_map();
+        assert_eq!(seed_to_location.preimage(46..47), vec![82..83]);
+        for seed in 0..200 {
+            let location = seed_to_location.get(seed);
+            let seeds = seed_to_location.preimage(location..location + 1);
+            assert!(seeds.iter().any(|seeds| seeds.contains(&seed)));
+            for seed in seeds.iter().flat_map(|seeds| seeds.clone()) {
+                assert_eq!(seed_to_location.get(seed), location);
+            }
+        }
+        let seed_to_soil = almanac.map("seed-to-soil").unwrap();
+        assert_eq!(seed_to_soil.preimage(50..53), [50..51, 98..100]);
+        assert_eq!(almanac.map("location-to-seed"), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let almanac = Almanac::parse_unchecked(
+            "seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2
+52 50 48
+0 40 12
+
+soil-to-location map:
+0 18446744073709551615 2",
+        )
+        .unwrap();
+        assert_eq!(
+            almanac.check(),
+            [
+                Finding::OddSeeds { line: 1, count: 3 },
+                Finding::Gap {
+                    map: "seed-to-soil".to_string(),
+                    line: 3,
+                    numbers: 0..40,
+                },
+                Finding::Overlap {
+                    map: "seed-to-soil".to_string(),
+                    line: 6,
+                    earlier_line: 5,
+                    numbers: 50..52,
+                },
+                Finding::Overflow {
+                    map: "soil-to-location".to_string(),
+                    line: 9,
+                },
+            ]
+        );
+        assert_eq!(
+            almanac.check()[2].to_string(),
+            "line 6: seed-to-soil range overlaps line 5 on 50..52, where line 5 wins"
+        );
+        assert!(!Puzzle::parse(ALMANAC)
+            .unwrap()
+            .check()
+            .iter()
+            .any(Finding::is_problem));
+        let error = Puzzle::parse("seeds: 1 2\n\nseed-to-location map:\n18446744073709551615 0 2")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+
+    #[test]
+    fn test_trace() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        let steps = almanac.trace(79);
+        assert_eq!(
+            steps
+                .iter()
+                .map(|step| (step.category.as_str(), step.number, step.line))
+                .collect::<Vec<_>>(),
+            [
+                ("soil", 81, Some(6)),
+                ("fertilizer", 81, None),
+                ("water", 81, None),
+                ("light", 74, Some(21)),
+                ("temperature", 78, Some(26)),
+                ("humidity", 78, None),
+                ("location", 82, Some(33)),
+            ]
+        );
+
+        let stages = almanac.trace_ranges(95..102);
+        assert_eq!(stages[0].0, "soil");
+        assert_eq!(
+            stages[0].1,
+            [
+                RangeStep {
+                    source: 95..98,
+                    destination: 97..100,
+                    line: Some(6),
+                },
+                RangeStep {
+                    source: 98..100,
+                    destination: 50..52,
+                    line: Some(5),
+                },
+                RangeStep {
+                    source: 100..102,
+                    destination: 100..102,
+                    line: None,
+                },
+            ]
+        );
+        let mut locations = stages
+            .last()
+            .unwrap()
+            .1
+            .iter()
+            .flat_map(|step| step.destination.clone())
+            .collect::<Vec<_>>();
+        locations.sort_unstable();
+        let mut expected_locations = (95..102)
+            .map(|seed| find_lowest_location_number(&almanac, &[seed]))
+            .collect::<Vec<_>>();
+        expected_locations.sort_unstable();
+        assert_eq!(locations, expected_locations);
+    }
+
+    #[test]
+    fn test_conversion_between_any_categories() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        let soil_to_light = almanac.map("soil-to-light").unwrap();
+        let chain = almanac.chain("soil", "light").unwrap();
+        assert_eq!(
+            chain
+                .iter()
+                .map(|map| map.destination.as_str())
+                .collect::<Vec<_>>(),
+            ["fertilizer", "water", "light"]
+        );
+        for soil in 0..200 {
+            assert_eq!(
+                soil_to_light.get(soil),
+                chain.iter().fold(soil, |number, map| {
+                    map_number_according_to_map(number, &map.ranges)
+                })
+            );
+        }
+        assert_eq!(almanac.conversion("water", "water").unwrap().get(7), 7);
+        assert_eq!(almanac.conversion("light", "soil"), None);
+        assert_eq!(almanac.conversion("seed", "sunlight"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |almanac: &str| {
+            let error = Puzzle::parse(almanac).unwrap_err();
+            (error.line, error.expected)
+        };
+        assert_eq!(
+            error("seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nseed-to-soil map:\n4 5 6"),
+            (6, "a map that is not a duplicate of line 3".to_string())
+        );
+        assert_eq!(
+            error("seeds: 1\n\nseed-to-soil map:\n\nseed-to-water map:"),
+            (
+                5,
+                "a single map from `seed`, which line 3 already converts to `soil`".to_string()
+            )
+        );
+        assert_eq!(
+            error("seeds: 1\n\nseed-to-location map:\n\nsoil-to-water map:\n\nwater-to-soil map:"),
+            (
+                5,
+                "maps without a cycle, but `soil` converts back to itself".to_string()
+            )
+        );
+        assert_eq!(
+            error("seeds: 1\n\nseed-to-soil map:\n\nwater-to-location map:"),
+            (
+                5,
+                "a `soil-to-<category> map:` section leading to `location`".to_string()
+            )
+        );
+        assert_eq!(
+            error("seeds: 1\n\nseed to soil map:"),
+            (3, "a map header like `seed-to-soil map:`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_map_ranges_according_to_map() {
+        let map = [
+            AlmanacMapRange {
+                destination_range_start: 50,
+                source_range_start: 98,
+                range_length: 2,
+                line: 0,
+            },
+            AlmanacMapRange {
+                destination_range_start: 52,
+                source_range_start: 50,
+                range_length: 48,
+                line: 0,
+            },
+        ];
+        let mut ranges = map_ranges_according_to_map(&[40..60, 95..110], &map);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, [40..50, 50..52, 52..62, 97..100, 100..110]);
+    }
+
+    #[test]
+    fn test_ranges_match_individual_seeds() {
+        let almanac = Puzzle::parse(ALMANAC).unwrap();
+        for seed_range in [0..100, 13..14, 50..98, 60..61, 97..101] {
+            let seeds = seed_range.clone().collect::<Vec<_>>();
+            assert_eq!(
+                find_lowest_location_in_ranges(&almanac, &[seed_range]),
+                find_lowest_location_number(&almanac, &seeds)
+            );
+        }
+    }
+}
@@ -0,0 +1,530 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time_ms: u32,
+    /// The record distance to beat.
+    pub distance_mm: u64,
+}
+
+/// The races of the puzzle input, read both ways.
+#[derive(Debug)]
+pub struct Races {
+    races: Vec<Race>,
+    /// The single race part 2 reads: the spaces between the numbers are just
+    /// bad kerning, so all of them together describe one long race. Reading
+    /// the numbers as one may overflow even when each of them fits.
+    kerned_race: Result<Race, ParseError>,
+}
+
+impl Races {
+    /// The races as part 1 reads them.
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    /// The single race part 2 reads, or why its numbers do not fit.
+    pub fn kerned_race(&self) -> Result<&Race, &ParseError> {
+        self.kerned_race.as_ref()
+    }
+}
+
+/// How a boat turns the time its button is held into distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoatModel {
+    /// Speed gained per ms the button is held, in mm/ms.
+    pub acceleration: u32,
+    /// Speed past which holding the button no longer charges the boat, in
+    /// mm/ms.
+    pub max_speed: Option<u32>,
+    /// Fraction of its speed the boat loses in every ms it moves, at least 0
+    /// and below 1.
+    pub friction: Option<f64>,
+}
+
+/// The boats of the puzzle.
+impl Default for BoatModel {
+    fn default() -> Self {
+        BoatModel {
+            acceleration: 1,
+            max_speed: None,
+            friction: None,
+        }
+    }
+}
+
+/// What a boat can do in a race.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaceAnalysis {
+    /// The hold times that beat the record, sorted and disjoint.
+    pub winning_hold_times: Vec<std::ops::Range<u32>>,
+    /// The shortest hold time that goes the furthest.
+    pub best_hold_time_ms: u32,
+    /// The furthest distance, in whole mm.
+    pub max_distance_mm: u128,
+}
+
+impl RaceAnalysis {
+    pub fn ways_to_win(&self) -> u32 {
+        self.winning_hold_times
+            .iter()
+            .map(|hold_times| hold_times.end - hold_times.start)
+            .sum()
+    }
+}
+
+impl BoatModel {
+    fn speed(&self, hold_time_ms: u32) -> u128 {
+        let speed = self.acceleration as u128 * hold_time_ms as u128;
+        self.max_speed
+            .map_or(speed, |max_speed| speed.min(max_speed as u128))
+    }
+
+    /// The distance covered without friction, which is exact.
+    fn exact_distance_mm(&self, race_time_ms: u32, hold_time_ms: u32) -> u128 {
+        self.speed(hold_time_ms) * (race_time_ms - hold_time_ms) as u128
+    }
+
+    /// The distance covered in a race of `race_time_ms` when holding the
+    /// button for `hold_time_ms`.
+    pub fn distance_mm(&self, race_time_ms: u32, hold_time_ms: u32) -> f64 {
+        match self.friction {
+            Some(friction) if friction > 0.0 => {
+                let travel_time_ms = (race_time_ms - hold_time_ms) as f64;
+                self.speed(hold_time_ms) as f64 * (1.0 - (1.0 - friction).powf(travel_time_ms))
+                    / friction
+            }
+            _ => self.exact_distance_mm(race_time_ms, hold_time_ms) as f64,
+        }
+    }
+
+    /// Finds the hold times that beat the record, the best hold time and the
+    /// furthest distance. Without friction the distance first grows and then
+    /// shrinks with the hold time and the results are exact. With friction
+    /// every hold time is tried.
+    pub fn analyze(&self, race: &Race) -> RaceAnalysis {
+        match (self.friction, self.max_speed) {
+            (Some(friction), _) if friction > 0.0 => self.scan(race),
+            _ if self.acceleration == 0 => RaceAnalysis {
+                winning_hold_times: Vec::new(),
+                best_hold_time_ms: 0,
+                max_distance_mm: 0,
+            },
+            (_, None) => {
+                let best_hold_time_ms = race.time_ms / 2;
+                let winning_hold_times = get_winning_hold_times(race, self.acceleration);
+                RaceAnalysis {
+                    winning_hold_times: match winning_hold_times.is_empty() {
+                        true => Vec::new(),
+                        false => vec![winning_hold_times],
+                    },
+                    best_hold_time_ms,
+                    max_distance_mm: self.exact_distance_mm(race.time_ms, best_hold_time_ms),
+                }
+            }
+            (_, Some(max_speed)) => self.analyze_capped(race, max_speed),
+        }
+    }
+
+    /// Without the cap the distance peaks at half the race time. From the
+    /// first hold time that reaches the maximum speed on, the distance only
+    /// shrinks, so the peak is at one of these.
+    fn analyze_capped(&self, race: &Race, max_speed: u32) -> RaceAnalysis {
+        let distance = |hold_time_ms| self.exact_distance_mm(race.time_ms, hold_time_ms);
+        let capped_hold_time_ms = max_speed.div_ceil(self.acceleration).min(race.time_ms);
+        let best_hold_time_ms = [
+            race.time_ms / 2,
+            race.time_ms - race.time_ms / 2,
+            capped_hold_time_ms.saturating_sub(1),
+            capped_hold_time_ms,
+        ]
+        .into_iter()
+        .min_by_key(|hold_time_ms| (std::cmp::Reverse(distance(*hold_time_ms)), *hold_time_ms))
+        .unwrap_or_default();
+        let max_distance_mm = distance(best_hold_time_ms);
+        let record = race.distance_mm as u128;
+        if max_distance_mm <= record {
+            return RaceAnalysis {
+                winning_hold_times: Vec::new(),
+                best_hold_time_ms,
+                max_distance_mm,
+            };
+        }
+        // The first hold time in `range` for which `is_past` holds, given
+        // that it holds for every hold time after that one.
+        let partition_point = |mut range: std::ops::Range<u32>, is_past: &dyn Fn(u32) -> bool| {
+            while !range.is_empty() {
+                let middle = range.start + (range.end - range.start) / 2;
+                match is_past(middle) {
+                    true => range.end = middle,
+                    false => range.start = middle + 1,
+                }
+            }
+            range.start
+        };
+        let first_win = partition_point(0..best_hold_time_ms, &|hold_time_ms| {
+            distance(hold_time_ms) > record
+        });
+        let end = partition_point(best_hold_time_ms..race.time_ms + 1, &|hold_time_ms| {
+            distance(hold_time_ms) <= record
+        });
+        let winning_hold_times = first_win..end;
+        RaceAnalysis {
+            winning_hold_times: vec![winning_hold_times],
+            best_hold_time_ms,
+            max_distance_mm,
+        }
+    }
+
+    /// Tries every hold time, which works for any distance model.
+    fn scan(&self, race: &Race) -> RaceAnalysis {
+        let mut winning_hold_times: Vec<std::ops::Range<u32>> = Vec::new();
+        let mut best_hold_time_ms = 0;
+        let mut max_distance_mm = 0.0;
+        for hold_time_ms in 0..=race.time_ms {
+            let distance_mm = self.distance_mm(race.time_ms, hold_time_ms);
+            if distance_mm > max_distance_mm {
+                best_hold_time_ms = hold_time_ms;
+                max_distance_mm = distance_mm;
+            }
+            if distance_mm > race.distance_mm as f64 {
+                match winning_hold_times.last_mut() {
+                    Some(hold_times) if hold_times.end == hold_time_ms => hold_times.end += 1,
+                    _ => winning_hold_times.push(hold_time_ms..hold_time_ms + 1),
+                }
+            }
+        }
+        RaceAnalysis {
+            winning_hold_times,
+            best_hold_time_ms,
+            max_distance_mm: max_distance_mm as u128,
+        }
+    }
+}
+
+/// Finds the hold times that beat the record without a maximum speed or
+/// friction, which form an interval around half the race time.
+///
+/// Holding for `h` ms covers `acceleration * h * (T - h)` mm, so the winning
+/// hold times are those with `h * (T - h) > D / acceleration`, rounded down
+/// as both sides are integers. The square root of the discriminant only
+/// gives an estimate of the lower root, which is then corrected so that
+/// holding for exactly the record distance does not count.
+fn get_winning_hold_times(race: &Race, acceleration: u32) -> std::ops::Range<u32> {
+    let time_ms = race.time_ms as u128;
+    let record = (race.distance_mm / acceleration as u64) as u128;
+    let wins = |hold_time_ms: u128| hold_time_ms * (time_ms - hold_time_ms) > record;
+    let discriminant = (time_ms * time_ms).saturating_sub(4 * record);
+    let mut first_win = (time_ms - discriminant.isqrt()) / 2;
+    while first_win > 0 && wins(first_win - 1) {
+        first_win -= 1;
+    }
+    while first_win <= time_ms / 2 && !wins(first_win) {
+        first_win += 1;
+    }
+    if first_win > time_ms / 2 {
+        return 0..0;
+    }
+    // Holding for no time never wins, so the end cannot overflow.
+    first_win as u32..race.time_ms - first_win as u32 + 1
+}
+
+/// Multiplies the ways to win each race, or `None` when the product does not
+/// fit in a u64.
+fn calculate_margin_of_error(races: &[Race]) -> Option<u64> {
+    let boat = BoatModel::default();
+    races.iter().try_fold(0, |acc: u64, race| {
+        let count = boat.analyze(race).ways_to_win() as u64;
+        match acc {
+            0 => Some(count),
+            _ => count.checked_mul(acc),
+        }
+    })
+}
+
+/// Parses `text`, a slice of the line's text, reporting a number too large
+/// for `T` as such rather than as a malformed number.
+fn parse_race_number<T: FromStr>(
+    line: &Line,
+    text: &str,
+    quantity: &str,
+    max: impl Display,
+) -> Result<T, ParseError> {
+    text.parse().map_err(|_| {
+        let expected = match !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
+            true => format!("a {} of at most {}", quantity, max),
+            false => format!("a {}", quantity),
+        };
+        line.error(text, expected)
+    })
+}
+
+/// Parses the numbers after `label`, both one by one and as a single number
+/// with the spaces between them left out. Only the numbers one by one have to
+/// fit.
+fn parse_race_line<T: FromStr>(
+    line: Line,
+    label: &str,
+    quantity: &str,
+    max: impl Display + Copy,
+) -> Result<(Vec<T>, Result<T, ParseError>), ParseError> {
+    let numbers = line
+        .text
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(line.text, format!("a `{}` line", label)))?;
+    let separate_numbers = numbers
+        .split_whitespace()
+        .map(|token| parse_race_number(&line, token, quantity, max))
+        .collect::<Result<Vec<T>, _>>()?;
+    let kerned_number = numbers.split_whitespace().collect::<String>();
+    let kerned_number = kerned_number.parse().map_err(|_| {
+        line.error(
+            numbers.trim(),
+            format!(
+                "a {} of at most {} when the numbers are read as one",
+                quantity, max
+            ),
+        )
+    });
+    Ok((separate_numbers, kerned_number))
+}
+
+fn parse_races(input: &str) -> Result<Races, ParseError> {
+    let mut lines = aoc_core::lines(input);
+    let time_line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a `Time:` line"))?;
+    let distance_line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a `Distance:` line"))?;
+    if let Some(line) = lines.next() {
+        return Err(line.error(line.text, "the end of the input after the distances"));
+    }
+    let (times, kerned_time_ms) = parse_race_line(time_line, "Time:", "time", u32::MAX)?;
+    let (distances, kerned_distance_mm) =
+        parse_race_line(distance_line, "Distance:", "distance", u64::MAX)?;
+    if times.len() != distances.len() {
+        return Err(distance_line.error(
+            distance_line.text,
+            format!("{} distances, one for each race", times.len()),
+        ));
+    }
+    Ok(Races {
+        races: times
+            .into_iter()
+            .zip(distances)
+            .map(|(time_ms, distance_mm)| Race {
+                time_ms,
+                distance_mm,
+            })
+            .collect(),
+        kerned_race: kerned_time_ms.and_then(|time_ms| {
+            Ok(Race {
+                time_ms,
+                distance_mm: kerned_distance_mm?,
+            })
+        }),
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_races(input)
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        calculate_margin_of_error(&races.races).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(races: &Self::Input) -> Answer {
+        match &races.kerned_race {
+            Ok(race) => calculate_margin_of_error(std::slice::from_ref(race))
+                .map_or(Answer::Unsolved, Answer::from),
+            Err(_) => Answer::Unsolved,
+        }
+    }
+
+    fn warnings(races: &Self::Input, parts: &[u8]) -> Vec<String> {
+        match &races.kerned_race {
+            Err(error) if parts.contains(&2) => {
+                vec![format!("part 2 has no race to solve, {}", error)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn get_strategies_for_race(race: &super::Race, acceleration: u32) -> Vec<u32> {
+        (0..=race.time_ms)
+            .filter(|&hold_time_ms| {
+                let travel_time_ms = race.time_ms - hold_time_ms;
+                let speed = hold_time_ms * acceleration;
+                speed as u64 * travel_time_ms as u64 > race.distance_mm
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_winning_hold_times_match_brute_force() {
+        for time_ms in 0..80 {
+            // Up to past the longest distance any hold time reaches.
+            for distance_mm in 0..=(time_ms as u64 * time_ms as u64 / 4 + 2) {
+                let race = super::Race {
+                    time_ms,
+                    distance_mm,
+                };
+                for acceleration in [1, 3] {
+                    assert_eq!(
+                        super::get_winning_hold_times(&race, acceleration).collect::<Vec<_>>(),
+                        get_strategies_for_race(&race, acceleration),
+                        "{:?}",
+                        race
+                    );
+                }
+            }
+        }
+        let race = super::Race {
+            time_ms: u32::MAX,
+            distance_mm: 0,
+        };
+        assert_eq!(super::get_winning_hold_times(&race, 1), 1..u32::MAX);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = Vec::from([
+            super::Race {
+                time_ms: 7,
+                distance_mm: 9,
+            },
+            super::Race {
+                time_ms: 15,
+                distance_mm: 40,
+            },
+            super::Race {
+                time_ms: 30,
+                distance_mm: 200,
+            },
+        ]);
+        assert_eq!(super::calculate_margin_of_error(&input), Some(288));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = Vec::from([super::Race {
+            time_ms: 71530,
+            distance_mm: 940200,
+        }]);
+        assert_eq!(super::calculate_margin_of_error(&input), Some(71503));
+    }
+
+    #[test]
+    fn test_parse_races() {
+        let races = super::parse_races(
+            "Time:      7  15   30
+Distance:  9  40  200",
+        )
+        .unwrap();
+        assert_eq!(super::calculate_margin_of_error(&races.races), Some(288));
+        assert_eq!(
+            races.kerned_race,
+            Ok(super::Race {
+                time_ms: 71530,
+                distance_mm: 940200,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_overflow() {
+        let error = super::parse_races(
+            "Time:      7  4294967296
+Distance:  9  40",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 15));
+        assert_eq!(error.expected, "a time of at most 4294967295");
+
+        let races = super::parse_races(
+            "Time:      7  15   30
+Distance:  9  40  18446744073709551615",
+        )
+        .unwrap();
+        assert_eq!(super::calculate_margin_of_error(&races.races), Some(0));
+        assert!(<super::Puzzle as aoc_core::Solution>::warnings(&races, &[1]).is_empty());
+        assert_eq!(
+            <super::Puzzle as aoc_core::Solution>::warnings(&races, &[2]).len(),
+            1
+        );
+        let error = races.kerned_race.unwrap_err();
+        assert_eq!((error.line, error.column), (2, 12));
+        assert_eq!(
+            error.expected,
+            "a distance of at most 18446744073709551615 when the numbers are read as one"
+        );
+    }
+
+    #[test]
+    fn test_exact_models_match_scan() {
+        for max_speed in [None, Some(0), Some(4), Some(7), Some(30)] {
+            for acceleration in [0, 1, 2, 5] {
+                let boat = super::BoatModel {
+                    acceleration,
+                    max_speed,
+                    friction: None,
+                };
+                for time_ms in 0..40 {
+                    for distance_mm in (0..=120).step_by(3) {
+                        let race = super::Race {
+                            time_ms,
+                            distance_mm,
+                        };
+                        let analysis = boat.analyze(&race);
+                        let scan = boat.scan(&race);
+                        assert_eq!(analysis.winning_hold_times, scan.winning_hold_times);
+                        assert_eq!(analysis.max_distance_mm, scan.max_distance_mm);
+                        assert_eq!(
+                            boat.exact_distance_mm(time_ms, analysis.best_hold_time_ms),
+                            scan.max_distance_mm,
+                            "{:?} {:?}",
+                            boat,
+                            race
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_friction() {
+        let boat = super::BoatModel {
+            acceleration: 1,
+            max_speed: None,
+            friction: Some(0.5),
+        };
+        // Holding for 4 ms gives a speed of 4, and the boat then covers 4, 2
+        // and 1 mm in the remaining 3 ms.
+        assert_eq!(boat.distance_mm(7, 4), 7.0);
+        // The speed halves for longer than an i32 of ms, never going past 2 mm.
+        assert_eq!(boat.distance_mm(u32::MAX, 1), 2.0);
+        let analysis = boat.analyze(&super::Race {
+            time_ms: 7,
+            distance_mm: 6,
+        });
+        assert_eq!(analysis.winning_hold_times.len(), 1);
+        assert_eq!(analysis.winning_hold_times[0], 4..6);
+        assert_eq!(
+            (analysis.best_hold_time_ms, analysis.max_distance_mm),
+            (5, 7)
+        );
+    }
+}
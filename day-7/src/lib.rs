@@ -0,0 +1,682 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+use itertools::Itertools;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    num::IntErrorKind,
+};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard = 10,
+    OnePair = 20,
+    TwoPair = 30,
+    ThreeOfAKind = 40,
+    FullHouse = 50,
+    FourOfAKind = 60,
+    FiveOfAKind = 70,
+}
+
+impl HandType {
+    /// Every category, from the weakest to the strongest.
+    pub const ALL: [HandType; 7] = [
+        HandType::HighCard,
+        HandType::OnePair,
+        HandType::TwoPair,
+        HandType::ThreeOfAKind,
+        HandType::FullHouse,
+        HandType::FourOfAKind,
+        HandType::FiveOfAKind,
+    ];
+}
+
+impl std::fmt::Display for HandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Bid {
+    labels: [char; 5],
+    bet: u32,
+    line: usize,
+}
+
+const CARD_LABELS: &str = "23456789TJQKA";
+
+/// The categories of the puzzle, for hands of any size: wild cards join the
+/// largest group of the other cards, as that always makes the strongest hand,
+/// and more than five equal cards still make five of a kind.
+pub fn standard_hand_type(group_sizes: &[usize], wild_cards: usize) -> HandType {
+    let largest = group_sizes.first().copied().unwrap_or(0) + wild_cards;
+    let second = group_sizes.get(1).copied().unwrap_or(0);
+    match (largest, second) {
+        (5.., _) => HandType::FiveOfAKind,
+        (4, _) => HandType::FourOfAKind,
+        (3, 2..) => HandType::FullHouse,
+        (3, _) => HandType::ThreeOfAKind,
+        (2, 2) => HandType::TwoPair,
+        (2, _) => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+/// How hands of Camel Cards are ranked.
+#[derive(Debug, Clone)]
+pub struct CamelCardsRules {
+    /// Card labels from the weakest to the strongest.
+    pub card_order: String,
+    /// Labels that stand in for whichever card makes the strongest hand.
+    pub wild_labels: String,
+    /// Category of a hand from the sizes of its groups of equal non-wild
+    /// cards, largest first, and its number of wild cards.
+    pub hand_type: fn(&[usize], usize) -> HandType,
+}
+
+impl CamelCardsRules {
+    /// The rules of part 1.
+    pub fn standard() -> Self {
+        CamelCardsRules {
+            card_order: CARD_LABELS.to_string(),
+            wild_labels: String::new(),
+            hand_type: standard_hand_type,
+        }
+    }
+
+    /// The rules of part 2, where `J` is a joker and the weakest card.
+    pub fn jokers() -> Self {
+        CamelCardsRules {
+            card_order: "J23456789TQKA".to_string(),
+            wild_labels: "J".to_string(),
+            hand_type: standard_hand_type,
+        }
+    }
+
+    /// Strength of a card, labels outside of the card order being weaker
+    /// than every card.
+    fn strength(&self, label: char) -> u8 {
+        self.card_order
+            .chars()
+            .position(|card| card == label)
+            .map_or(0, |position| position as u8 + 1)
+    }
+
+    pub fn categorize(&self, labels: &[char]) -> HandType {
+        let mut wild_cards = 0;
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for &label in labels {
+            if self.wild_labels.contains(label) {
+                wild_cards += 1;
+            } else {
+                *counts.entry(label).or_default() += 1;
+            }
+        }
+        let group_sizes = counts.into_values().sorted().rev().collect::<Vec<_>>();
+        (self.hand_type)(&group_sizes, wild_cards)
+    }
+
+    /// The label the wild cards of a hand stand in for: one of its largest
+    /// group of other cards, or the strongest card when they are all wild.
+    pub fn wild_stand_in(&self, labels: &[char]) -> Option<char> {
+        let (wild, other): (Vec<char>, Vec<char>) = labels
+            .iter()
+            .partition(|&&label| self.wild_labels.contains(label));
+        if wild.is_empty() {
+            return None;
+        }
+        other
+            .iter()
+            .counts()
+            .into_iter()
+            .max_by_key(|&(&label, count)| (count, self.strength(label)))
+            .map(|(&label, _)| label)
+            .or_else(|| {
+                self.card_order
+                    .chars()
+                    .rev()
+                    .find(|&label| !self.wild_labels.contains(label))
+            })
+    }
+
+    pub fn hand(&self, labels: &[char]) -> Hand {
+        Hand {
+            hand_type: self.categorize(labels),
+            strengths: labels.iter().map(|&label| self.strength(label)).collect(),
+            labels: labels.to_vec(),
+        }
+    }
+}
+
+/// A hand ranked under a set of rules: first by its category, then card by
+/// card.
+#[derive(Debug, Clone)]
+pub struct Hand {
+    pub labels: Vec<char>,
+    pub hand_type: HandType,
+    strengths: Vec<u8>,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hand_type, &self.strengths).cmp(&(other.hand_type, &other.strengths))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+fn parse_bid(line: Line) -> Result<Bid, ParseError> {
+    let mut parts = line.text.split_whitespace();
+    let labels = parts
+        .next()
+        .ok_or_else(|| line.missing("a hand of cards"))?;
+    if let Some((index, label)) = labels
+        .char_indices()
+        .find(|(_, label)| !CARD_LABELS.contains(*label))
+    {
+        return Err(line.error(
+            &labels[index..index + label.len_utf8()],
+            "a card label (one of 23456789TJQKA)",
+        ));
+    }
+    let cards = labels.chars().collect::<Vec<char>>();
+    let card_count = cards.len();
+    let labels = cards
+        .try_into()
+        .map_err(|_| line.error(labels, format!("a hand of 5 cards, not {}", card_count)))?;
+    let bet = parts
+        .next()
+        .ok_or_else(|| line.missing("a bet after the hand"))?;
+    if let Some(extra) = parts.next() {
+        return Err(line.error(extra, "the end of the line after the bet"));
+    }
+    let bet = bet.parse::<u32>().map_err(|error| match error.kind() {
+        IntErrorKind::PosOverflow => line.error(bet, format!("a bet of at most {}", u32::MAX)),
+        _ => line.error(bet, "a bet (a whole number)"),
+    })?;
+    Ok(Bid {
+        labels,
+        bet,
+        line: line.number,
+    })
+}
+
+/// A hand dealt a second time, which leaves the ranks of the two bids
+/// ambiguous.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateHand {
+    pub labels: String,
+    pub line: usize,
+    pub earlier_line: usize,
+}
+
+impl std::fmt::Display for DuplicateHand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} repeats the hand {} of line {}, so the ranks of their bids are ambiguous",
+            self.line, self.labels, self.earlier_line
+        )
+    }
+}
+
+pub fn find_duplicate_hands(bids: &[Bid]) -> Vec<DuplicateHand> {
+    let mut first_lines = HashMap::new();
+    bids.iter()
+        .filter_map(|bid| {
+            let earlier_line = *first_lines.entry(bid.labels).or_insert(bid.line);
+            (earlier_line != bid.line).then(|| DuplicateHand {
+                labels: bid.labels.iter().collect(),
+                line: bid.line,
+                earlier_line,
+            })
+        })
+        .collect()
+}
+
+/// The hands of the bids with their bets, from the weakest to the strongest.
+fn rank_hands(bids: &[Bid], rules: &CamelCardsRules) -> Vec<(Hand, u32)> {
+    bids.iter()
+        .map(|bid| (rules.hand(&bid.labels), bid.bet))
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .collect()
+}
+
+pub fn calculate_total_winnings(bids: &[Bid], rules: &CamelCardsRules) -> u64 {
+    rank_hands(bids, rules)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (_, bet))| bet as u64 * (i + 1) as u64)
+        .sum()
+}
+
+/// The card that puts a hand above the hand ranked just below it, when both
+/// are of the same category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TieBreak {
+    /// Position of the card in the hand, counting from 1.
+    pub position: usize,
+    pub label: char,
+    pub beaten_label: char,
+}
+
+/// Why a hand got its rank and winnings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub rank: u64,
+    pub labels: String,
+    pub hand_type: HandType,
+    pub wild_cards_as: Option<char>,
+    pub tie_break: Option<TieBreak>,
+    pub bet: u32,
+    pub winnings: u64,
+}
+
+/// Explains every hand, from the weakest to the strongest.
+pub fn explain(bids: &[Bid], rules: &CamelCardsRules) -> Vec<Explanation> {
+    let ranked = rank_hands(bids, rules);
+    ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (hand, bet))| {
+            let rank = i as u64 + 1;
+            let tie_break = i
+                .checked_sub(1)
+                .map(|below| &ranked[below].0)
+                .filter(|below| below.hand_type == hand.hand_type)
+                .and_then(|below| {
+                    (0..hand.labels.len())
+                        .find(|&index| hand.strengths[index] != below.strengths[index])
+                        .map(|index| TieBreak {
+                            position: index + 1,
+                            label: hand.labels[index],
+                            beaten_label: below.labels[index],
+                        })
+                });
+            Explanation {
+                rank,
+                labels: hand.labels.iter().collect(),
+                hand_type: hand.hand_type,
+                wild_cards_as: rules.wild_stand_in(&hand.labels),
+                tie_break,
+                bet: *bet,
+                winnings: *bet as u64 * rank,
+            }
+        })
+        .collect()
+}
+
+/// How often each category comes up among a number of hands.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Distribution {
+    counts: BTreeMap<HandType, u64>,
+    total: u64,
+}
+
+impl Distribution {
+    fn add(&mut self, hand_type: HandType) {
+        *self.counts.entry(hand_type).or_default() += 1;
+        self.total += 1;
+    }
+
+    pub fn count(&self, hand_type: HandType) -> u64 {
+        self.counts.get(&hand_type).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn probability(&self, hand_type: HandType) -> f64 {
+        self.count(hand_type) as f64 / self.total.max(1) as f64
+    }
+}
+
+/// Categorizes every hand of `hand_size` cards, each card being any label of
+/// the card order.
+pub fn exact_distribution(rules: &CamelCardsRules, hand_size: usize) -> Distribution {
+    let mut distribution = Distribution::default();
+    for hand in itertools::repeat_n(rules.card_order.chars(), hand_size).multi_cartesian_product() {
+        distribution.add(rules.categorize(&hand));
+    }
+    distribution
+}
+
+/// The SplitMix64 generator, enough to draw reproducible random hands.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `bound`, with a bias too small to matter here.
+    fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+}
+
+/// Categorizes `samples` hands of `hand_size` cards drawn uniformly from the
+/// card order, the same `seed` drawing the same hands.
+pub fn simulate_distribution(
+    rules: &CamelCardsRules,
+    hand_size: usize,
+    samples: u64,
+    seed: u64,
+) -> Distribution {
+    let labels = rules.card_order.chars().collect::<Vec<_>>();
+    let mut random = SplitMix64(seed);
+    let mut distribution = Distribution::default();
+    let mut hand = vec![' '; hand_size];
+    for _ in 0..samples {
+        for card in hand.iter_mut() {
+            *card = labels[random.below(labels.len())];
+        }
+        distribution.add(rules.categorize(&hand));
+    }
+    distribution
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Bid>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input).map(parse_bid).collect()
+    }
+
+    fn part1(bids: &Self::Input) -> Answer {
+        calculate_total_winnings(bids, &CamelCardsRules::standard()).into()
+    }
+
+    fn part2(bids: &Self::Input) -> Answer {
+        calculate_total_winnings(bids, &CamelCardsRules::jokers()).into()
+    }
+
+    fn warnings(bids: &Self::Input, _parts: &[u8]) -> Vec<String> {
+        find_duplicate_hands(bids)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        assert_eq!(
+            calculate_total_winnings(&Puzzle::parse(input).unwrap(), &CamelCardsRules::standard()),
+            6440
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        assert_eq!(
+            calculate_total_winnings(&Puzzle::parse(input).unwrap(), &CamelCardsRules::jokers()),
+            5905
+        );
+    }
+
+    #[test]
+    fn test_hand_order() {
+        let rules = CamelCardsRules::jokers();
+        let hand = |labels: &str| rules.hand(&labels.chars().collect::<Vec<_>>());
+        assert_eq!(hand("KTJJT").hand_type, HandType::FourOfAKind);
+        assert!(hand("KTJJT") > hand("QQQJA"));
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+        assert!(hand("23456") < hand("J3456"));
+
+        let aces_low = CamelCardsRules {
+            card_order: "A23456789TJQK".to_string(),
+            wild_labels: "29".to_string(),
+            ..CamelCardsRules::standard()
+        };
+        let hand = |labels: &str| aces_low.hand(&labels.chars().collect::<Vec<_>>());
+        assert_eq!(hand("A29KQ").hand_type, HandType::ThreeOfAKind);
+        assert!(hand("AAKK3") < hand("KKAA3"));
+    }
+
+    /// The category of a hand without wild cards, from which groups of equal
+    /// cards it contains.
+    fn hand_type_of_groups(hand: &[char]) -> HandType {
+        let counts = hand.iter().counts().into_values().collect::<Vec<_>>();
+        let has_group = |size| counts.iter().any(|&count| count >= size);
+        let groups_of = |size| counts.iter().filter(|&&count| count >= size).count();
+        if has_group(5) {
+            HandType::FiveOfAKind
+        } else if has_group(4) {
+            HandType::FourOfAKind
+        } else if has_group(3) && groups_of(2) >= 2 {
+            HandType::FullHouse
+        } else if has_group(3) {
+            HandType::ThreeOfAKind
+        } else if groups_of(2) >= 2 {
+            HandType::TwoPair
+        } else if has_group(2) {
+            HandType::OnePair
+        } else {
+            HandType::HighCard
+        }
+    }
+
+    /// Checks the category of every hand of up to `max_size` cards against
+    /// the best category of all the hands its wild cards can stand in for.
+    fn check_against_substitution(labels: &str, wild_labels: &str, max_size: usize) {
+        let rules = CamelCardsRules {
+            card_order: format!("{}{}", wild_labels, labels),
+            wild_labels: wild_labels.to_string(),
+            ..CamelCardsRules::standard()
+        };
+        let all_labels = rules.card_order.chars().collect::<Vec<_>>();
+        for size in 1..=max_size {
+            for hand in
+                itertools::repeat_n(all_labels.iter().copied(), size).multi_cartesian_product()
+            {
+                let best = hand
+                    .iter()
+                    .map(|&label| match wild_labels.contains(label) {
+                        true => labels.chars().collect::<Vec<_>>(),
+                        false => vec![label],
+                    })
+                    .multi_cartesian_product()
+                    .map(|substituted| hand_type_of_groups(&substituted))
+                    .max()
+                    .unwrap();
+                assert_eq!(rules.categorize(&hand), best, "{:?}", hand);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wild_cards_match_substitution() {
+        check_against_substitution("ABCDE", "J", 5);
+        check_against_substitution("ABCD", "XY", 5);
+        check_against_substitution("ABC", "J", 7);
+
+        let rules = CamelCardsRules::standard();
+        let hand = |labels: &str| rules.hand(&labels.chars().collect::<Vec<_>>());
+        assert_eq!(hand("AAABBB").hand_type, HandType::FullHouse);
+        assert!(hand("AAABBB") >= hand("AAABBC"));
+    }
+
+    #[test]
+    fn test_largest_bet() {
+        let bids = Puzzle::parse("32T3K 4294967295\nT55J5 2").unwrap();
+        assert_eq!(
+            calculate_total_winnings(&bids, &CamelCardsRules::standard()),
+            4294967299
+        );
+        assert_eq!(
+            explain(&bids, &CamelCardsRules::standard())[0].winnings,
+            4294967295
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let explanations = explain(&Puzzle::parse(input).unwrap(), &CamelCardsRules::jokers());
+        assert_eq!(
+            explanations
+                .iter()
+                .map(|explanation| explanation.labels.as_str())
+                .collect::<Vec<_>>(),
+            ["32T3K", "KK677", "T55J5", "QQQJA", "KTJJT"]
+        );
+        assert_eq!(
+            explanations[4],
+            Explanation {
+                rank: 5,
+                labels: "KTJJT".to_string(),
+                hand_type: HandType::FourOfAKind,
+                wild_cards_as: Some('T'),
+                tie_break: Some(TieBreak {
+                    position: 1,
+                    label: 'K',
+                    beaten_label: 'Q',
+                }),
+                bet: 220,
+                winnings: 1100,
+            }
+        );
+        assert_eq!(explanations[1].wild_cards_as, None);
+        assert_eq!(explanations[1].tie_break, None);
+        assert_eq!(
+            explanations
+                .iter()
+                .map(|explanation| explanation.winnings)
+                .sum::<u64>(),
+            5905
+        );
+    }
+
+    #[test]
+    fn test_distributions() {
+        for rules in [CamelCardsRules::standard(), CamelCardsRules::jokers()] {
+            let exact = exact_distribution(&rules, 5);
+            assert_eq!(exact.total(), 13u64.pow(5));
+            if rules.wild_labels.is_empty() {
+                assert_eq!(
+                    HandType::ALL.map(|hand_type| exact.count(hand_type)),
+                    [154440, 171600, 25740, 17160, 1560, 780, 13]
+                );
+            }
+            let simulated = simulate_distribution(&rules, 5, 20_000, 7);
+            assert_eq!(simulated, simulate_distribution(&rules, 5, 20_000, 7));
+            for hand_type in HandType::ALL {
+                let difference = exact.probability(hand_type) - simulated.probability(hand_type);
+                assert!(difference.abs() < 0.015, "{}: {}", hand_type, difference);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "32T3K 765
+T55X5 684";
+        let error = Puzzle::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "X");
+
+        for (input, column, text, expected) in [
+            ("32T3 765", 1, "32T3", "a hand of 5 cards, not 4"),
+            ("32T3KA 765", 1, "32T3KA", "a hand of 5 cards, not 6"),
+            ("32T3K", 6, "", "a bet after the hand"),
+            ("32T3K 7x5", 7, "7x5", "a bet (a whole number)"),
+            ("32T3K -765", 7, "-765", "a bet (a whole number)"),
+            (
+                "32T3K 4294967296",
+                7,
+                "4294967296",
+                "a bet of at most 4294967295",
+            ),
+            (
+                "32T3K 765 28",
+                11,
+                "28",
+                "the end of the line after the bet",
+            ),
+        ] {
+            let error = Puzzle::parse(input).unwrap_err();
+            assert_eq!(
+                (
+                    error.line,
+                    error.column,
+                    error.text.as_str(),
+                    error.expected.as_str()
+                ),
+                (1, column, text, expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_duplicate_hands() {
+        let input = "32T3K 765
+T55J5 684
+32T3K 28
+KTJJT 220
+32T3K 483";
+        let bids = Puzzle::parse(input).unwrap();
+        let duplicates = find_duplicate_hands(&bids);
+        assert_eq!(
+            duplicates
+                .iter()
+                .map(|duplicate| (duplicate.line, duplicate.earlier_line))
+                .collect::<Vec<_>>(),
+            [(3, 1), (5, 1)]
+        );
+        assert_eq!(
+            duplicates[0].to_string(),
+            "line 3 repeats the hand 32T3K of line 1, so the ranks of their bids are ambiguous"
+        );
+        assert_eq!(Puzzle::warnings(&bids, &[1, 2]).len(), 2);
+    }
+}
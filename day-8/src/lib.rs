@@ -0,0 +1,272 @@
+use aoc_core::{Answer, Line, ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<Instruction>,
+    nodes: HashMap<String, Vec<String>>,
+}
+
+fn parse_instructions(lines: &[Line]) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    for line in lines {
+        for (index, instruction) in line.text.trim_end().char_indices() {
+            instructions.push(match instruction {
+                'L' => Instruction::Left,
+                'R' => Instruction::Right,
+                _ => {
+                    return Err(line.error(
+                        &line.text[index..index + instruction.len_utf8()],
+                        "an instruction, `L` or `R`",
+                    ))
+                }
+            });
+        }
+    }
+    Ok(instructions)
+}
+
+fn parse_map(lines: &[Line]) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let mut connections = Vec::new();
+    for line in lines {
+        let (current_node, connected_nodes) = line
+            .text
+            .trim_end()
+            .split_once(" = ")
+            .ok_or_else(|| line.missing("` = ` after the node"))?;
+        let (left_node, right_node) = connected_nodes
+            .strip_prefix('(')
+            .and_then(|connected_nodes| connected_nodes.strip_suffix(')'))
+            .and_then(|connected_nodes| connected_nodes.split_once(", "))
+            .ok_or_else(|| line.error(connected_nodes, "connected nodes as `(<left>, <right>)`"))?;
+        connections.push((line, current_node, [left_node, right_node]));
+    }
+    let nodes = connections
+        .iter()
+        .map(|(_, current_node, _)| *current_node)
+        .collect::<HashSet<&str>>();
+    if let Some((line, connected_node)) =
+        connections.iter().find_map(|(line, _, connected_nodes)| {
+            connected_nodes
+                .iter()
+                .find(|connected_node| !nodes.contains(*connected_node))
+                .map(|connected_node| (line, connected_node))
+        })
+    {
+        return Err(line.error(connected_node, "a node defined in the network"));
+    }
+    Ok(connections
+        .into_iter()
+        .map(|(_, current_node, connected_nodes)| {
+            (
+                current_node.to_string(),
+                connected_nodes.map(String::from).to_vec(),
+            )
+        })
+        .collect())
+}
+
+/// The node an instruction leads to, or `None` when `current_node` is not in
+/// the network.
+fn get_next_node<'a>(
+    current_node: &str,
+    instruction: Instruction,
+    nodes: &'a HashMap<String, Vec<String>>,
+) -> Option<&'a str> {
+    let connected_nodes = nodes.get(current_node)?;
+    let next_node = match instruction {
+        Instruction::Left => connected_nodes.first(),
+        Instruction::Right => connected_nodes.last(),
+    };
+    next_node.map(String::as_str)
+}
+
+/// Counts the steps from `AAA` to `ZZZ`, or `None` when the network lacks
+/// either of them.
+fn calculate_number_of_steps_to_reach_destination(network: &Network) -> Option<usize> {
+    let starting_node = "AAA";
+    let destination_node = "ZZZ";
+    let nodes = &network.nodes;
+    if !nodes.contains_key(starting_node) || !nodes.contains_key(destination_node) {
+        return None;
+    }
+    let mut current_node = starting_node;
+    let mut instructions = network.instructions.iter().copied().cycle();
+    let mut steps_taken = 0;
+    while current_node != destination_node {
+        let instruction = instructions.next()?;
+        current_node = get_next_node(current_node, instruction, nodes)?;
+        steps_taken += 1;
+    }
+    Some(steps_taken)
+}
+
+fn lcm(nums: &[usize]) -> usize {
+    nums.iter()
+        .fold(1, |a, &b| a * b / gcd_of_two_numbers(a, b))
+}
+
+fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd_of_two_numbers(b, a % b)
+}
+
+/// Counts the steps until every node ending in `A` is at a node ending in `Z`
+/// at once, or `None` when no node ends in `A`.
+fn calculate_number_of_steps_to_reach_destination_parallel(network: &Network) -> Option<usize> {
+    let mut instructions = network.instructions.iter().copied().cycle();
+    let nodes = &network.nodes;
+    let starting_nodes: HashSet<&str> = HashSet::from_iter(
+        nodes
+            .keys()
+            .map(String::as_str)
+            .filter(|x| x.ends_with('A')),
+    );
+    if starting_nodes.is_empty() {
+        return None;
+    }
+    let mut current_nodes = starting_nodes.clone();
+    let mut steps_taken = 0;
+    let mut reached_destinations_with_step_count: HashMap<&str, usize> = HashMap::new();
+    while !current_nodes.is_empty() {
+        let instruction = instructions.next()?;
+        current_nodes = current_nodes
+            .iter()
+            .map(|x| get_next_node(x, instruction, nodes))
+            .collect::<Option<_>>()?;
+        steps_taken += 1;
+
+        if current_nodes.iter().any(|x| x.ends_with('Z')) {
+            reached_destinations_with_step_count.insert(
+                current_nodes.iter().find(|x| x.ends_with('Z')).unwrap(),
+                steps_taken,
+            );
+
+            current_nodes.retain(|x| !x.ends_with('Z'));
+        }
+    }
+
+    Some(lcm(&reached_destinations_with_step_count
+        .values()
+        .cloned()
+        .collect::<Vec<usize>>()))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let sections = aoc_core::sections(input);
+        let [instructions, network] = &sections[..] else {
+            return Err(ParseError::end_of_input(
+                input,
+                "the instructions and the network separated by a blank line",
+            ));
+        };
+        Ok(Network {
+            instructions: parse_instructions(instructions)?,
+            nodes: parse_map(network)?,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Answer {
+        calculate_number_of_steps_to_reach_destination(network)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(network: &Self::Input) -> Answer {
+        calculate_number_of_steps_to_reach_destination_parallel(network)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1_example_1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            calculate_number_of_steps_to_reach_destination(&Puzzle::parse(input).unwrap()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_part_1_example_2() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            calculate_number_of_steps_to_reach_destination(&Puzzle::parse(input).unwrap()),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(
+            calculate_number_of_steps_to_reach_destination_parallel(&Puzzle::parse(input).unwrap()),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_missing_start() {
+        let input = "LR
+
+11A = (11B, 11Z)
+11B = (11Z, 11Z)
+11Z = (11B, 11Z)";
+        let network = Puzzle::parse(input).unwrap();
+        assert_eq!(Puzzle::part1(&network), Answer::Unsolved);
+        assert_eq!(Puzzle::part2(&network), Answer::Number(2));
+
+        let network = Puzzle::parse("L\n\nBBB = (BBB, BBB)").unwrap();
+        assert_eq!(Puzzle::part2(&network), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)";
+        let error = Puzzle::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 13));
+        assert_eq!(error.text, "ZZZ");
+    }
+}